@@ -0,0 +1,76 @@
+//! Parses an addon's `addon.toml` manifest declaring external dependencies
+//! (CocoaPods pods, SwiftPM packages, system frameworks), so `setup_addons`
+//! can aggregate them across every selected addon and wire them into the
+//! generated Podfile, `Package.swift`, `project.yml`, and `CMakeLists.txt`.
+
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+pub const ADDON_MANIFEST_FILE_NAME: &str = "addon.toml";
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AddonManifest {
+    #[serde(default)]
+    pub dependencies: AddonDependencies,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AddonDependencies {
+    #[serde(default)]
+    pub pods: Vec<PodDependency>,
+    #[serde(default)]
+    pub swift_packages: Vec<SwiftPackageDependency>,
+    #[serde(default)]
+    pub frameworks: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PodDependency {
+    pub name: String,
+    #[serde(default)]
+    pub version: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SwiftPackageDependency {
+    pub name: String,
+    pub url: String,
+    #[serde(default)]
+    pub from: Option<String>,
+}
+
+/// Reads `<addon_dir>/addon.toml`; addons without one declare no external
+/// dependencies.
+pub fn load(addon_dir: &Path) -> Option<AddonManifest> {
+    let content = std::fs::read_to_string(addon_dir.join(ADDON_MANIFEST_FILE_NAME)).ok()?;
+    toml::from_str(&content).ok()
+}
+
+/// Aggregates manifests across every addon's source directory, de-duplicating
+/// pods/packages/frameworks by name.
+pub fn aggregate(addon_dirs: &[PathBuf]) -> AddonDependencies {
+    let mut deps = AddonDependencies::default();
+
+    for dir in addon_dirs {
+        let Some(manifest) = load(dir) else {
+            continue;
+        };
+        for pod in manifest.dependencies.pods {
+            if !deps.pods.iter().any(|p| p.name == pod.name) {
+                deps.pods.push(pod);
+            }
+        }
+        for pkg in manifest.dependencies.swift_packages {
+            if !deps.swift_packages.iter().any(|p| p.name == pkg.name) {
+                deps.swift_packages.push(pkg);
+            }
+        }
+        for framework in manifest.dependencies.frameworks {
+            if !deps.frameworks.contains(&framework) {
+                deps.frameworks.push(framework);
+            }
+        }
+    }
+
+    deps
+}
@@ -0,0 +1,106 @@
+//! CPU-architecture selection for generated projects, plus a `lipo`-based
+//! post-build check that the produced binary and bundled static libs
+//! actually contain the requested slices — mixing thin and fat archives is
+//! a common silent failure on Apple Silicon.
+
+use crate::error::{GeneratorError, Result};
+use std::path::Path;
+use std::process::Command;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ArchMode {
+    /// Leave `ARCHS`/`CMAKE_OSX_ARCHITECTURES` unset; builds for the host only.
+    Native,
+    Universal,
+    Arm64,
+    X86_64,
+}
+
+impl ArchMode {
+    pub fn parse(raw: &str) -> Result<Self> {
+        match raw {
+            "native" => Ok(ArchMode::Native),
+            "universal" => Ok(ArchMode::Universal),
+            "arm64" => Ok(ArchMode::Arm64),
+            "x86_64" => Ok(ArchMode::X86_64),
+            _ => Err(GeneratorError::Other(format!(
+                "Invalid arch: {}. Must be 'native', 'universal', 'arm64', or 'x86_64'",
+                raw
+            ))),
+        }
+    }
+
+    /// `ARCHS`/`CMAKE_OSX_ARCHITECTURES` value; `None` for `native` means
+    /// "leave the default (host-only) behavior alone".
+    pub fn archs_value(&self) -> Option<&'static str> {
+        match self {
+            ArchMode::Native => None,
+            ArchMode::Universal => Some("arm64 x86_64"),
+            ArchMode::Arm64 => Some("arm64"),
+            ArchMode::X86_64 => Some("x86_64"),
+        }
+    }
+
+    /// Slices a built binary must contain for this mode (empty for `native`).
+    pub fn required_slices(&self) -> Vec<&'static str> {
+        match self.archs_value() {
+            Some(value) => value.split(' ').collect(),
+            None => Vec::new(),
+        }
+    }
+}
+
+/// Runs `lipo -archs` on `binary_path` and warns (doesn't fail the build)
+/// if any of `required_slices` is missing.
+pub fn verify_archs(binary_path: &Path, required_slices: &[&str]) -> Result<()> {
+    if required_slices.is_empty() || !binary_path.exists() {
+        return Ok(());
+    }
+
+    let output = Command::new("lipo")
+        .args(["-archs", &binary_path.display().to_string()])
+        .output()
+        .map_err(|e| GeneratorError::Other(format!("Failed to run lipo: {}", e)))?;
+
+    if !output.status.success() {
+        eprintln!(
+            "Warning: lipo couldn't inspect '{}': {}",
+            binary_path.display(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+        return Ok(());
+    }
+
+    let present: Vec<String> = String::from_utf8_lossy(&output.stdout)
+        .split_whitespace()
+        .map(str::to_string)
+        .collect();
+
+    for slice in required_slices {
+        if !present.iter().any(|p| p == slice) {
+            eprintln!(
+                "Warning: '{}' is missing the '{}' slice (has: {}); mixing thin and fat \
+                 archives will fail at link or launch time",
+                binary_path.display(),
+                slice,
+                present.join(", ")
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Verifies the built app binary plus any bundled static libs all contain
+/// every slice `required_slices` asks for.
+pub fn verify_build(
+    app_binary: &Path,
+    static_libs: &[std::path::PathBuf],
+    required_slices: &[&str],
+) -> Result<()> {
+    verify_archs(app_binary, required_slices)?;
+    for lib in static_libs {
+        verify_archs(lib, required_slices)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,349 @@
+//! Generates a Swift bridging header plus wrapper from an addon's public C++
+//! interface, so addon functionality is reachable from the `swiftui` entry
+//! mode without hand-written glue.
+//!
+//! This does line-level scanning for `class Name { ... };` blocks and public
+//! method declarations -- it covers the addon surface this generator itself
+//! produces (primitive or simple-value signatures), not arbitrary C++.
+
+use crate::config::BindingsConfig;
+use crate::error::Result;
+use std::fs;
+use std::path::Path;
+
+#[derive(Debug, Clone)]
+struct ExportedClass {
+    name: String,
+    /// Header file name (e.g. `"Foo.h"`) this class was scanned out of, so
+    /// the generated trampoline implementation can `#include` it and see the
+    /// real class definition.
+    header_file: String,
+    methods: Vec<ExportedMethod>,
+}
+
+#[derive(Debug, Clone)]
+struct ExportedMethod {
+    name: String,
+    return_type: String,
+    params: Vec<(String, String)>,
+}
+
+/// Scan every `.h` file directly under `headers_dir`, and if any exported
+/// class was found, write a bridging header, its trampoline implementations,
+/// and a Swift wrapper.
+pub fn generate_bindings(
+    headers_dir: &Path,
+    config: &BindingsConfig,
+    out_bridging_header: &Path,
+    out_swift: &Path,
+) -> Result<bool> {
+    let classes = scan_headers(headers_dir, config)?;
+    if classes.is_empty() {
+        return Ok(false);
+    }
+
+    fs::write(out_bridging_header, render_bridging_header(&classes, config))?;
+    fs::write(
+        out_bridging_header.with_extension("mm"),
+        render_bridging_impl(&classes, config, out_bridging_header),
+    )?;
+    fs::write(out_swift, render_swift_wrapper(&classes, config))?;
+    Ok(true)
+}
+
+fn scan_headers(dir: &Path, config: &BindingsConfig) -> Result<Vec<ExportedClass>> {
+    let mut classes = Vec::new();
+    if !dir.exists() {
+        return Ok(classes);
+    }
+
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().and_then(|e| e.to_str()) != Some("h") {
+            continue;
+        }
+        let Some(file_name) = path.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+        let content = fs::read_to_string(&path)?;
+        classes.extend(scan_source(&content, file_name, config));
+    }
+    Ok(classes)
+}
+
+fn scan_source(content: &str, header_file: &str, config: &BindingsConfig) -> Vec<ExportedClass> {
+    let mut classes = Vec::new();
+    let mut current: Option<ExportedClass> = None;
+    let mut in_public = false;
+
+    for line in content.lines() {
+        let trimmed = line.trim();
+
+        if let Some(name) = trimmed
+            .strip_prefix("class ")
+            .and_then(|rest| rest.split(|c: char| c == ':' || c == '{').next())
+        {
+            let name = name.trim().to_string();
+            current = should_export(&name, config).then(|| ExportedClass {
+                name,
+                header_file: header_file.to_string(),
+                methods: Vec::new(),
+            });
+            in_public = false;
+            continue;
+        }
+
+        if trimmed == "};" {
+            if let Some(class) = current.take() {
+                classes.push(class);
+            }
+            in_public = false;
+            continue;
+        }
+
+        match trimmed {
+            "public:" => in_public = true,
+            "private:" | "protected:" => in_public = false,
+            _ => {
+                if in_public {
+                    if let (Some(class), Some(method)) = (current.as_mut(), parse_method(trimmed)) {
+                        if !config.exclude.contains(&method.name) {
+                            class.methods.push(method);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    classes
+}
+
+fn should_export(name: &str, config: &BindingsConfig) -> bool {
+    if config.exclude.iter().any(|e| e == name) {
+        return false;
+    }
+    config.classes.is_empty() || config.classes.iter().any(|c| c == name)
+}
+
+/// Parses a single public member declaration like `int doThing(float x);`
+/// into return type, name, and parameters. Returns `None` for anything that
+/// isn't a simple function declaration (constructors, fields, comments, ...).
+fn parse_method(line: &str) -> Option<ExportedMethod> {
+    let line = line.trim_end_matches(';').trim();
+    if !line.ends_with(')') || !line.contains('(') {
+        return None;
+    }
+
+    let open = line.find('(')?;
+    let (head, rest) = line.split_at(open);
+    let params_str = rest.trim_start_matches('(').trim_end_matches(')');
+
+    let mut head_parts: Vec<&str> = head.trim().split_whitespace().collect();
+    let name = head_parts.pop()?.to_string();
+    let return_type = head_parts.join(" ");
+    if return_type.is_empty() || name.is_empty() {
+        return None;
+    }
+
+    let params = if params_str.trim().is_empty() {
+        Vec::new()
+    } else {
+        params_str
+            .split(',')
+            .filter_map(|p| {
+                let mut parts: Vec<&str> = p.trim().split_whitespace().collect();
+                let pname = parts.pop()?.trim_start_matches('*').to_string();
+                let ptype = parts.join(" ");
+                Some((ptype, pname))
+            })
+            .collect()
+    };
+
+    Some(ExportedMethod {
+        name,
+        return_type,
+        params,
+    })
+}
+
+/// Maps a C++ parameter/return type to a Swift primitive, or `OpaquePointer`
+/// if it's not a primitive or is explicitly listed in `opaque_types`.
+fn map_type(cpp_type: &str, config: &BindingsConfig) -> &'static str {
+    if config.opaque_types.iter().any(|t| t == cpp_type) {
+        return "OpaquePointer";
+    }
+    match cpp_type {
+        "int" | "int32_t" => "Int32",
+        "float" => "Float",
+        "double" => "Double",
+        "bool" => "Bool",
+        "void" => "Void",
+        "std::string" | "const std::string&" => "String",
+        _ => "OpaquePointer",
+    }
+}
+
+fn render_bridging_header(classes: &[ExportedClass], config: &BindingsConfig) -> String {
+    let mut out = String::from(
+        "#pragma once\n\n// Generated by oflike-gen bindings. Do not edit by hand.\n#include <oflike/ofApp.h>\n\n",
+    );
+    for class in classes {
+        out.push_str(&format!("class {};\n", class.name));
+    }
+    out.push('\n');
+    for class in classes {
+        out.push_str(&format!("// {} trampolines\n", class.name));
+        out.push_str(&format!(
+            "extern \"C\" {}* {}{}_create();\n",
+            class.name, config.prefix, class.name
+        ));
+        out.push_str(&format!(
+            "extern \"C\" void {}{}_destroy({}* self);\n",
+            config.prefix, class.name, class.name
+        ));
+        for method in &class.methods {
+            let params = method
+                .params
+                .iter()
+                .map(|(ty, name)| format!("{} {}", ty, name))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let self_param = if params.is_empty() {
+                format!("{}* self", class.name)
+            } else {
+                format!("{}* self, {}", class.name, params)
+            };
+            out.push_str(&format!(
+                "extern \"C\" {} {}{}_{}({});\n",
+                method.return_type, config.prefix, class.name, method.name, self_param
+            ));
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Renders the `.mm` trampoline bodies the bridging header declares --
+/// `#include`s each scanned header so the real class definitions are visible,
+/// then forwards every call straight through to the C++ object behind
+/// `self`.
+fn render_bridging_impl(
+    classes: &[ExportedClass],
+    config: &BindingsConfig,
+    bridging_header_path: &Path,
+) -> String {
+    let header_name = bridging_header_path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("Bridging.h");
+    let mut out = format!(
+        "// Generated by oflike-gen bindings. Do not edit by hand.\n#include \"{}\"\n",
+        header_name
+    );
+    let mut included: Vec<&str> = Vec::new();
+    for class in classes {
+        if !included.contains(&class.header_file.as_str()) {
+            out.push_str(&format!("#include \"{}\"\n", class.header_file));
+            included.push(&class.header_file);
+        }
+    }
+    out.push('\n');
+
+    for class in classes {
+        out.push_str(&format!(
+            "extern \"C\" {}* {}{}_create() {{\n    return new {}();\n}}\n",
+            class.name, config.prefix, class.name, class.name
+        ));
+        out.push_str(&format!(
+            "extern \"C\" void {}{}_destroy({}* self) {{\n    delete self;\n}}\n",
+            config.prefix, class.name, class.name
+        ));
+        for method in &class.methods {
+            let params = method
+                .params
+                .iter()
+                .map(|(ty, name)| format!("{} {}", ty, name))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let self_param = if params.is_empty() {
+                format!("{}* self", class.name)
+            } else {
+                format!("{}* self, {}", class.name, params)
+            };
+            let args = method
+                .params
+                .iter()
+                .map(|(_, name)| name.as_str())
+                .collect::<Vec<_>>()
+                .join(", ");
+            let call = format!("self->{}({})", method.name, args);
+            let body = if method.return_type == "void" {
+                format!("    {};\n", call)
+            } else {
+                format!("    return {};\n", call)
+            };
+            out.push_str(&format!(
+                "extern \"C\" {} {}{}_{}({}) {{\n{}}}\n",
+                method.return_type, config.prefix, class.name, method.name, self_param, body
+            ));
+        }
+        out.push('\n');
+    }
+    out
+}
+
+fn render_swift_wrapper(classes: &[ExportedClass], config: &BindingsConfig) -> String {
+    let mut out = String::from("// Generated by oflike-gen bindings. Do not edit by hand.\nimport Foundation\n\n");
+    for class in classes {
+        out.push_str(&format!("@objc public class {} : NSObject {{\n", class.name));
+        out.push_str("    private let handle: OpaquePointer\n\n");
+        out.push_str(&format!(
+            "    @objc public override init() {{\n        handle = {}{}_create()\n        super.init()\n    }}\n\n",
+            config.prefix, class.name
+        ));
+        out.push_str(&format!(
+            "    deinit {{\n        {}{}_destroy(handle)\n    }}\n\n",
+            config.prefix, class.name
+        ));
+        for method in &class.methods {
+            let swift_params = method
+                .params
+                .iter()
+                .map(|(ty, name)| format!("{}: {}", name, map_type(ty, config)))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let ret = map_type(&method.return_type, config);
+            let arrow = if ret == "Void" {
+                String::new()
+            } else {
+                format!(" -> {}", ret)
+            };
+            let call_args = method
+                .params
+                .iter()
+                .map(|(_, name)| name.clone())
+                .collect::<Vec<_>>()
+                .join(", ");
+            let call = if call_args.is_empty() {
+                format!("{}{}_{}(handle)", config.prefix, class.name, method.name)
+            } else {
+                format!(
+                    "{}{}_{}(handle, {})",
+                    config.prefix, class.name, method.name, call_args
+                )
+            };
+            let body = if ret == "Void" {
+                format!("        {}\n", call)
+            } else {
+                format!("        return {}\n", call)
+            };
+            out.push_str(&format!(
+                "    @objc public func {}({}){} {{\n{}    }}\n",
+                method.name, swift_params, arrow, body
+            ));
+        }
+        out.push_str("}\n\n");
+    }
+    out
+}
@@ -14,9 +14,25 @@ bundle_id_prefix = "com.example"
 [paths]
 # oflike_metal_root = "/usr/local/lib/oflike-metal"
 # addons_dir = "~/addons"
+# registry_url = "https://addons.example.com"
 
 [templates]
 default_template = "basic"
+
+# Additional addon sources, searched in order before `paths.registry_url` for
+# `namespace/id[@version]` specs (built-in Core/Native names are matched
+# first). Add one [[registry]] table per source:
+# [[registry]]
+# name = "team"
+# url = "https://addons.internal.example.com"
+# index = "index.json"
+
+# Curated `new --profile <name>` bundles, layered over [defaults]. List them
+# with `oflike-gen new --profile list`. Example:
+# [profiles.audio-reactive]
+# description = "FFT/audio-input addons wired up out of the box"
+# template = "basic"
+# addons = ["ofxNetwork"]
 "#,
         get_author_from_git().unwrap_or_else(|| "Unknown".to_string())
     );
@@ -0,0 +1,121 @@
+use crate::config::ProjectConfig;
+use crate::error::{GeneratorError, Result};
+use crate::lockfile::LockFile;
+use crate::registry;
+use std::path::Path;
+use std::process::Command;
+
+/// Re-materialize every addon pinned in `oflike.lock` into `project/addons`
+/// (registry-resolved addons) and `project/vendor` (git-submodule addons),
+/// without consulting the registry index -- each entry already carries the
+/// concrete mirror URL/commit it was resolved against.
+pub fn execute(project: &str, verbose: bool) -> Result<()> {
+    let project_path = Path::new(project);
+    let lock = LockFile::load_or_default(project_path)?;
+
+    warn_on_lockfile_drift(project_path, &lock);
+
+    if lock.addons.is_empty() && lock.vendored.is_empty() {
+        println!("No addons recorded in oflike.lock; nothing to install.");
+        return Ok(());
+    }
+
+    if !lock.addons.is_empty() {
+        let addons_dir = project_path.join("addons");
+        std::fs::create_dir_all(&addons_dir)?;
+
+        for locked in &lock.addons {
+            if verbose {
+                println!(
+                    "Restoring {}/{}@{} from {}",
+                    locked.namespace, locked.id, locked.version, locked.resolved_url
+                );
+            }
+
+            let bytes = registry::fetch_and_verify(&locked.resolved_url, &locked.sha256)?;
+            let dest = addons_dir.join(&locked.id);
+            registry::extract_archive(&bytes, &dest)?;
+
+            println!("✅ Restored {}/{}@{}", locked.namespace, locked.id, locked.version);
+        }
+    }
+
+    for vendored in &lock.vendored {
+        restore_vendored(project_path, vendored, verbose)?;
+    }
+
+    Ok(())
+}
+
+/// Warns (without failing the install) if `oflike.lock` has drifted from the
+/// registry-resolved custom addons declared in `oflike.toml` -- e.g. someone hand-added an
+/// entry to `oflike.toml` without running `add-addon`, so `install` is about
+/// to restore a set that no longer matches what the project declares.
+fn warn_on_lockfile_drift(project_path: &Path, lock: &LockFile) {
+    let Ok(manifest_content) = std::fs::read_to_string(project_path.join("oflike.toml")) else {
+        return;
+    };
+    let Ok(config) = toml::from_str::<ProjectConfig>(&manifest_content) else {
+        return;
+    };
+
+    let declared: Vec<String> = config
+        .addons
+        .custom
+        .iter()
+        .filter(|c| c.mode == "registry")
+        .map(|c| c.name.clone())
+        .collect();
+    for warning in lock.staleness(&declared) {
+        eprintln!("Warning: {}", warning);
+    }
+}
+
+/// Materializes a single vendored addon at its pinned SHA: clones the
+/// submodule into `vendor/<name>/` if it isn't already checked out, then
+/// checks out the locked commit.
+fn restore_vendored(
+    project_path: &Path,
+    vendored: &crate::lockfile::VendoredAddon,
+    verbose: bool,
+) -> Result<()> {
+    let relative_dest = format!("vendor/{}", vendored.name);
+    let dest = project_path.join(&relative_dest);
+
+    if !dest.exists() {
+        if verbose {
+            println!("Cloning {} into {}", vendored.url, relative_dest);
+        }
+        std::fs::create_dir_all(project_path.join("vendor"))?;
+        let output = Command::new("git")
+            .args(["clone", &vendored.url, &relative_dest])
+            .current_dir(project_path)
+            .output()?;
+        if !output.status.success() {
+            return Err(GeneratorError::Other(format!(
+                "Failed to clone '{}': {}",
+                vendored.name,
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+    }
+
+    if verbose {
+        println!("Checking out {} @ {}", vendored.name, vendored.sha);
+    }
+    let checkout = Command::new("git")
+        .args(["checkout", "--quiet", &vendored.sha])
+        .current_dir(&dest)
+        .output()?;
+    if !checkout.status.success() {
+        return Err(GeneratorError::Other(format!(
+            "Failed to check out {} @ {}: {}",
+            vendored.name,
+            vendored.sha,
+            String::from_utf8_lossy(&checkout.stderr).trim()
+        )));
+    }
+
+    println!("✅ Restored {} @ {}", vendored.name, &vendored.sha[..vendored.sha.len().min(12)]);
+    Ok(())
+}
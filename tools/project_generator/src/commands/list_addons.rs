@@ -1,7 +1,25 @@
+use crate::config::WorkspaceConfig;
 use crate::error::Result;
 use crate::utils::*;
+use std::collections::BTreeMap;
+use std::path::Path;
 
 pub fn execute(available: bool, project: &str, verbose: bool) -> Result<()> {
+    execute_with_workspace(available, project, false, verbose)
+}
+
+/// `workspace` mode aggregates every member's addons into one
+/// addon -> [members] view instead of listing a single project's addons.
+pub fn execute_with_workspace(
+    available: bool,
+    project: &str,
+    workspace: bool,
+    verbose: bool,
+) -> Result<()> {
+    if workspace {
+        return list_workspace_addons(Path::new(project), verbose);
+    }
+
     if available {
         println!("Core Addons:");
         for addon in core_addons() {
@@ -13,6 +31,25 @@ pub fn execute(available: bool, project: &str, verbose: bool) -> Result<()> {
         for addon in native_addons() {
             println!("  - {}", addon);
         }
+
+        let config = crate::config::resolve_config(
+            Path::new(project),
+            &crate::config::ConfigOverride::default(),
+        )?;
+        for reg in &config.registry {
+            println!();
+            println!("{} ({}):", reg.name, reg.url);
+            match crate::registry::load_named_index(reg) {
+                Ok(index) => {
+                    for addon in &index.addons {
+                        println!("  - {}/{}", addon.namespace, addon.id);
+                    }
+                }
+                Err(e) => {
+                    eprintln!("Warning: registry '{}' unavailable: {}", reg.name, e);
+                }
+            }
+        }
     } else {
         if verbose {
             println!("Listing addons in project: {}", project);
@@ -23,3 +60,38 @@ pub fn execute(available: bool, project: &str, verbose: bool) -> Result<()> {
 
     Ok(())
 }
+
+fn list_workspace_addons(root: &Path, verbose: bool) -> Result<()> {
+    let ws = WorkspaceConfig::load(root)?;
+
+    let mut by_addon: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for member in &ws.members {
+        let manifest = root.join(member).join("oflike.toml");
+        let Ok(content) = std::fs::read_to_string(&manifest) else {
+            if verbose {
+                eprintln!("Warning: '{}' has no oflike.toml", member);
+            }
+            continue;
+        };
+        let Ok(project) = toml::from_str::<crate::config::ProjectConfig>(&content) else {
+            continue;
+        };
+
+        for addon in &project.addons.core {
+            by_addon.entry(addon.clone()).or_default().push(member.clone());
+        }
+        for addon in &project.addons.custom {
+            by_addon
+                .entry(addon.name.clone())
+                .or_default()
+                .push(member.clone());
+        }
+    }
+
+    println!("Workspace addon usage:");
+    for (addon, members) in &by_addon {
+        println!("  - {}: {}", addon, members.join(", "));
+    }
+
+    Ok(())
+}
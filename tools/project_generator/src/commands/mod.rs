@@ -0,0 +1,9 @@
+pub mod add_addon;
+pub mod init;
+pub mod install;
+pub mod list_addons;
+pub mod new;
+pub mod remove_addon;
+pub mod validate;
+pub mod watch;
+pub mod workspace;
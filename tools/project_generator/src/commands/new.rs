@@ -1,4 +1,4 @@
-use crate::config::{get_author_from_git, load_global_config, Config};
+use crate::config::{get_author_from_git, resolve_config, Config, ConfigOverride};
 use crate::error::{GeneratorError, Result};
 use crate::utils::*;
 use std::fs;
@@ -10,23 +10,64 @@ pub fn execute(
     addons: Option<&str>,
     addon_mode: &str,
     path: Option<&str>,
-    template: &str,
+    template: Option<&str>,
+    profile: Option<&str>,
     bundle_id: Option<&str>,
     author: Option<&str>,
+    vcs: &str,
     no_git: bool,
     no_readme: bool,
+    bindings: bool,
     verbose: bool,
+    overrides: &ConfigOverride,
+    signing: &str,
+    entitlements: &crate::signing::EntitlementsConfig,
+    generator_name: &str,
+    arch: &str,
+    project_type: &str,
 ) -> Result<()> {
+    // Resolve layered config up front: `--profile list` only needs this much.
+    let config = resolve_config(Path::new("."), overrides)?;
+
+    if profile == Some("list") {
+        print_profiles(&config);
+        return Ok(());
+    }
+
+    let profile_config = match profile {
+        Some(name) => Some(config.profiles.get(name).cloned().ok_or_else(|| {
+            GeneratorError::Other(format!(
+                "No profile named '{}' (run `oflike-gen new --profile list` to see what's configured)",
+                name
+            ))
+        })?),
+        None => None,
+    };
+
+    // CLI args > profile > defaults.
+    let template = template
+        .map(String::from)
+        .or_else(|| profile_config.as_ref().and_then(|p| p.template.clone()))
+        .unwrap_or_else(|| config.templates.default_template.clone());
+    let template = template.as_str();
+
     // Validate inputs
     validate_project_name(project_name)?;
     validate_addon_mode(addon_mode)?;
     validate_template(template)?;
-
-    // Load global config
-    let config = load_global_config();
+    validate_generator(generator_name)?;
+    validate_entry_mode(&config.defaults.entry_mode)?;
+    let arch_mode = crate::arch::ArchMode::parse(arch)?;
+    let project_type = crate::project_type::ProjectType::parse(project_type)?;
+    let vcs_mode = if no_git {
+        eprintln!("Warning: --no-git is deprecated, use --vcs none instead");
+        crate::vcs::VersionControl::None
+    } else {
+        crate::vcs::VersionControl::parse(vcs)?
+    };
 
     // Determine project path (default to <oflike-root>/apps/<project-name>)
-    let project_base = resolve_project_base_path(path, config.as_ref())?;
+    let project_base = resolve_project_base_path(path, Some(&config))?;
     let project_path = project_base.join(project_name);
 
     // Check if project already exists
@@ -41,17 +82,29 @@ pub fn execute(
         validate_bundle_id(id)?;
         id.to_string()
     } else {
-        let prefix = config
-            .as_ref()
-            .map(|c| c.defaults.bundle_id_prefix.as_str())
-            .unwrap_or("com.example");
-        generate_bundle_id(project_name, prefix)
+        // A CLI --bundle-id-prefix override beats the profile; otherwise the
+        // profile beats whatever `config.defaults.bundle_id_prefix` resolved to.
+        let prefix = if overrides.bundle_id_prefix.is_some() {
+            config.defaults.bundle_id_prefix.clone()
+        } else {
+            profile_config
+                .as_ref()
+                .and_then(|p| p.bundle_id_prefix.clone())
+                .unwrap_or_else(|| config.defaults.bundle_id_prefix.clone())
+        };
+        generate_bundle_id(project_name, &prefix)
     };
 
     // Get author
     let author_name = author
         .map(String::from)
-        .or_else(|| config.as_ref().map(|c| c.defaults.author.clone()))
+        .or_else(|| {
+            if config.defaults.author.is_empty() {
+                None
+            } else {
+                Some(config.defaults.author.clone())
+            }
+        })
         .or_else(get_author_from_git)
         .unwrap_or_else(|| "Unknown".to_string());
 
@@ -64,7 +117,7 @@ pub fn execute(
     }
 
     // Create project structure
-    create_project_structure(&project_path)?;
+    create_project_structure(&project_path, project_type)?;
 
     // Generate files based on template
     generate_template_files(
@@ -73,47 +126,144 @@ pub fn execute(
         template,
         &bundle_id,
         &author_name,
+        project_type,
     )?;
 
-    // Parse and link/copy addons
-    let addon_list = parse_addons(addons)?;
-    if !addon_list.is_empty() {
-        setup_addons(&project_path, &addon_list, addon_mode, verbose)?;
+    // Parse and link/copy addons: an explicit --addons list beats the
+    // profile's curated default set.
+    let addon_list = if addons.is_some() {
+        parse_addons(addons)?
+    } else {
+        profile_config
+            .as_ref()
+            .and_then(|p| p.addons.clone())
+            .unwrap_or_default()
+    };
+    // Write oflike.toml before addons are set up: `generate_addon_bindings`
+    // (invoked from `setup_addons`) reads `[bindings] enabled` and
+    // `[entry] mode` back off disk to decide whether to bridge an addon's
+    // C++ interface, and needs the manifest to already exist to do that.
+    write_initial_manifest(
+        &project_path,
+        project_name,
+        &bundle_id,
+        &author_name,
+        &addon_list,
+        bindings,
+        &config.defaults.entry_mode,
+    )?;
+
+    let addon_dependencies = if !addon_list.is_empty() {
+        setup_addons(&project_path, &addon_list, addon_mode, verbose)?
+    } else {
+        crate::addon_manifest::AddonDependencies::default()
+    };
+
+    if !addon_dependencies.pods.is_empty() {
+        crate::podfile::generate_podfile(
+            &project_path,
+            project_name,
+            "13.0",
+            &addon_dependencies.pods,
+        )?;
+        crate::podfile::run_pod_install(&project_path, verbose)?;
     }
 
-    // Generate build files
-    generate_cmake_file(&project_path, project_name, &addon_list)?;
-    generate_xcodegen_file(&project_path, project_name, &bundle_id, &addon_list)?;
+    // Probe the installed Xcode toolchain so build files target the real
+    // SDK instead of an assumed version.
+    let xcode_env = crate::xcode::probe()?;
+    if verbose {
+        println!("  SDK: {} ({})", xcode_env.sdk_path, xcode_env.sdk_version);
+        println!("  Developer dir: {}", xcode_env.developer_dir);
+    }
 
-    // Generate .gitignore
-    generate_gitignore(&project_path)?;
+    // Resolve code-signing identity and render entitlements (if requested)
+    // before the build files that reference them.
+    let signing_mode = crate::signing::SigningMode::parse(signing);
+    let resolved_signing =
+        crate::signing::resolve(&project_path, project_name, &signing_mode, entitlements)?;
+    if verbose {
+        println!(
+            "  Signing: {}",
+            if resolved_signing.identity.is_empty() {
+                "none"
+            } else {
+                &resolved_signing.identity
+            }
+        );
+    }
+
+    // Generate build files via the selected backend
+    let generator = crate::generators::resolve(generator_name);
+    let ctx = crate::generators::ProjectContext {
+        project_path: project_path.clone(),
+        project_name: project_name.to_string(),
+        bundle_id: bundle_id.clone(),
+        author: author_name.clone(),
+        addon_list: addon_list.clone(),
+        xcode_env,
+        signing: resolved_signing,
+        addon_dependencies,
+        arch: arch_mode,
+        project_type,
+    };
+    generator.generate_build_files(&ctx)?;
+
+    // Keep editor tooling (clangd) in sync with the addon set. The oflike.toml
+    // written above holds the default BuildConfig, so its default
+    // cpp_standard is used directly.
+    crate::compile_db::generate(
+        &project_path,
+        &addon_list,
+        &ctx.xcode_env,
+        &crate::config::BuildConfig::default().cpp_standard,
+        generator_name != "swiftpm",
+    )?;
+
+    // Update oflike.toml with the (normalized) hash of each build file we
+    // just generated, so `validate --fix` can later tell stale boilerplate
+    // from hand edits.
+    record_generated_hashes(&project_path, project_name, &bundle_id, &ctx)?;
+
+    // Generate the VCS-appropriate ignore file (skipped for `--vcs none`)
+    generate_ignore_file(&project_path, vcs_mode)?;
 
     // Generate README
     if !no_readme {
         generate_readme(&project_path, project_name)?;
     }
 
-    // Initialize git
-    if !no_git {
-        init_git(&project_path, verbose)?;
-    }
+    // Initialize the chosen VCS backend (no-op for `--vcs none`)
+    vcs_mode.init(&project_path, verbose)?;
 
-    // Generate Xcode project via XcodeGen
-    run_xcodegen(&project_path, verbose)?;
+    generator.run_after_generate(&ctx, verbose)?;
 
     println!("✅ Project '{}' created successfully!", project_name);
     println!("   Path: {}", project_path.display());
-    println!("   Xcode: {}.xcodeproj", project_name);
+    println!("   Generator: {}", generator.summary());
     println!();
     println!("Next steps:");
     println!("  cd \"{}\"", project_path.display());
-    println!("  open {}.xcodeproj", project_name);
-    println!("  # Re-run `xcodegen generate` after editing project.yml");
+    match generator_name {
+        "cmake" => {
+            println!("  mkdir build && cd build && cmake .. -G Xcode");
+        }
+        "swiftpm" => {
+            println!("  swift build");
+        }
+        _ => {
+            println!("  open {}.xcodeproj", project_name);
+            println!("  # Re-run `xcodegen generate` after editing project.yml");
+        }
+    }
 
     Ok(())
 }
 
-fn create_project_structure(project_path: &Path) -> Result<()> {
+fn create_project_structure(
+    project_path: &Path,
+    project_type: crate::project_type::ProjectType,
+) -> Result<()> {
     fs::create_dir_all(project_path)?;
     fs::create_dir_all(project_path.join("src"))?;
     fs::create_dir_all(project_path.join("data"))?;
@@ -131,6 +281,13 @@ fn create_project_structure(project_path: &Path) -> Result<()> {
 "#,
     )?;
 
+    // Frameworks keep their public headers separate from implementation
+    // sources, so consumers can -I the framework's header search path
+    // without pulling in internals.
+    if project_type == crate::project_type::ProjectType::Framework {
+        fs::create_dir_all(project_path.join("include"))?;
+    }
+
     Ok(())
 }
 
@@ -140,9 +297,21 @@ fn generate_template_files(
     _template: &str,
     bundle_id: &str,
     _author: &str,
+    project_type: crate::project_type::ProjectType,
 ) -> Result<()> {
+    use crate::project_type::ProjectType;
+
     let class_name = to_pascal_case(project_name);
 
+    if project_type.is_library() {
+        generate_library_class(project_path, &class_name)?;
+        if project_type == ProjectType::Framework {
+            generate_umbrella_header(project_path, project_name, &class_name)?;
+            generate_info_plist(project_path, project_name, bundle_id, project_type)?;
+        }
+        return Ok(());
+    }
+
     // Generate header file
     let header_content = format!(
         r#"#pragma once
@@ -240,7 +409,92 @@ extern "C" ofBaseApp* ofCreateApp() {{
 
     // Generate SwiftUI entry and Info.plist
     generate_swiftui_entry(project_path, &class_name)?;
-    generate_info_plist(project_path, project_name, bundle_id)?;
+    generate_info_plist(project_path, project_name, bundle_id, project_type)?;
+
+    Ok(())
+}
+
+/// `framework`/`static-lib` projects get a plain class with no app-loop base
+/// class or `ofCreateApp` factory — those only make sense for an executable.
+fn generate_library_class(project_path: &Path, class_name: &str) -> Result<()> {
+    let header_content = format!(
+        r#"#pragma once
+
+class {0} {{
+public:
+    void setup();
+    void update();
+    void draw();
+}};
+"#,
+        class_name
+    );
+
+    fs::write(
+        project_path.join("src").join(format!("{}.h", class_name)),
+        header_content,
+    )?;
+
+    let impl_content = format!(
+        r#"#include "{0}.h"
+
+void {0}::setup() {{
+    // Initialization
+}}
+
+void {0}::update() {{
+    // Update logic
+}}
+
+void {0}::draw() {{
+    // Draw logic
+}}
+"#,
+        class_name
+    );
+
+    fs::write(
+        project_path.join("src").join(format!("{}.cpp", class_name)),
+        impl_content,
+    )?;
+
+    Ok(())
+}
+
+/// Emits a framework's umbrella header (re-exporting the project's public
+/// class header) and module map, the two extra artifacts XcodeGen's
+/// `type: framework` needs beyond what an app target requires.
+fn generate_umbrella_header(project_path: &Path, project_name: &str, class_name: &str) -> Result<()> {
+    let umbrella_content = format!(
+        r#"#pragma once
+
+// Umbrella header for the {} framework: re-exports every public header
+// consumers should see.
+#include "{}.h"
+"#,
+        project_name, class_name
+    );
+
+    fs::write(
+        project_path.join("include").join(format!("{}.h", project_name)),
+        umbrella_content,
+    )?;
+
+    let modulemap_content = format!(
+        r#"framework module {0} {{
+    umbrella header "{0}.h"
+
+    export *
+    module * {{ export * }}
+}}
+"#,
+        project_name
+    );
+
+    fs::write(
+        project_path.join("include").join("module.modulemap"),
+        modulemap_content,
+    )?;
 
     Ok(())
 }
@@ -291,10 +545,50 @@ struct {}App: App {{
         project_path.join("src").join("PerformanceMonitor.swift"),
     )?;
 
+    generate_project_bridging_header(project_path)?;
+
+    Ok(())
+}
+
+/// Writes `src/Bridging.h`, the single header `SWIFT_OBJC_BRIDGING_HEADER`
+/// points at (Xcode only allows one). It starts out just re-exporting the
+/// framework's own bridging header; `generate_addon_bindings` appends an
+/// `#include` line here for each addon whose bindings it generates, so
+/// per-addon bridging headers actually get compiled in.
+fn generate_project_bridging_header(project_path: &Path) -> Result<()> {
+    let path = project_path.join("src").join("Bridging.h");
+    if path.exists() {
+        return Ok(());
+    }
+    fs::write(
+        &path,
+        "#pragma once\n\n// Generated by oflike-gen. Addon bridging headers are appended below as\n// `add-addon` generates bindings for them -- see `bindings.rs`.\n#include \"../../../src/platform/bridge/oflike-metal-Bridging-Header.h\"\n",
+    )?;
     Ok(())
 }
 
-fn generate_info_plist(project_path: &Path, project_name: &str, bundle_id: &str) -> Result<()> {
+fn generate_info_plist(
+    project_path: &Path,
+    project_name: &str,
+    bundle_id: &str,
+    project_type: crate::project_type::ProjectType,
+) -> Result<()> {
+    use crate::project_type::ProjectType;
+
+    // NSApplication-specific keys only make sense for an executable; a
+    // framework's bundle has no principal class or OS-version floor to set.
+    let app_only_keys = match project_type {
+        ProjectType::App => concat!(
+            "    <key>NSPrincipalClass</key>\n    <string>NSApplication</string>\n",
+            "    <key>LSMinimumSystemVersion</key>\n    <string>13.0</string>\n",
+        ),
+        _ => "",
+    };
+    let package_type = match project_type {
+        ProjectType::Framework => "FMWK",
+        _ => "APPL",
+    };
+
     let plist_content = format!(
         r#"<?xml version="1.0" encoding="UTF-8"?>
 <!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
@@ -304,20 +598,18 @@ fn generate_info_plist(project_path: &Path, project_name: &str, bundle_id: &str)
     <string>{}</string>
     <key>CFBundleIdentifier</key>
     <string>{}</string>
+    <key>CFBundlePackageType</key>
+    <string>{}</string>
     <key>CFBundleVersion</key>
     <string>1.0.0</string>
     <key>CFBundleShortVersionString</key>
     <string>1.0.0</string>
     <key>CFBundleExecutable</key>
     <string>$(EXECUTABLE_NAME)</string>
-    <key>NSPrincipalClass</key>
-    <string>NSApplication</string>
-    <key>LSMinimumSystemVersion</key>
-    <string>13.0</string>
-</dict>
+{}</dict>
 </plist>
 "#,
-        project_name, bundle_id
+        project_name, bundle_id, package_type, app_only_keys
     );
 
     fs::write(
@@ -328,6 +620,27 @@ fn generate_info_plist(project_path: &Path, project_name: &str, bundle_id: &str)
     Ok(())
 }
 
+/// Prints every configured `[profiles.<name>]` entry and its purpose, for
+/// `oflike-gen new --profile list`.
+fn print_profiles(config: &Config) {
+    if config.profiles.is_empty() {
+        println!("No profiles configured. Add a [profiles.<name>] table to .oflike-gen.toml.");
+        return;
+    }
+
+    println!("Available profiles:");
+    let mut names: Vec<&String> = config.profiles.keys().collect();
+    names.sort();
+    for name in names {
+        let profile = &config.profiles[name];
+        if profile.description.is_empty() {
+            println!("  - {}", name);
+        } else {
+            println!("  - {}: {}", name, profile.description);
+        }
+    }
+}
+
 fn parse_addons(addons: Option<&str>) -> Result<Vec<String>> {
     if let Some(addon_str) = addons {
         let mut addon_list = Vec::new();
@@ -347,19 +660,123 @@ fn parse_addons(addons: Option<&str>) -> Result<Vec<String>> {
     }
 }
 
+/// Writes `oflike.toml` up front, before addons are set up, so
+/// `generate_addon_bindings` (which reads `[bindings] enabled` back off
+/// disk) has something to read during `setup_addons`. `record_generated_hashes`
+/// fills in the `[generated]` table afterwards, once the build files it
+/// hashes actually exist.
+fn write_initial_manifest(
+    project_path: &Path,
+    project_name: &str,
+    bundle_id: &str,
+    author_name: &str,
+    addon_list: &[String],
+    bindings_enabled: bool,
+    entry_mode: &str,
+) -> Result<()> {
+    let project_config = crate::config::ProjectConfig {
+        project: crate::config::ProjectInfo {
+            name: project_name.to_string(),
+            version: "1.0.0".to_string(),
+            author: author_name.to_string(),
+            bundle_id: bundle_id.to_string(),
+        },
+        entry: crate::config::EntryConfig {
+            mode: entry_mode.to_string(),
+        },
+        addons: crate::config::AddonsConfig {
+            core: addon_list
+                .iter()
+                .filter(|a| core_addons().contains(&a.as_str()))
+                .cloned()
+                .collect(),
+            custom: Vec::new(),
+        },
+        build: crate::config::BuildConfig::default(),
+        paths: crate::config::ProjectPaths::default(),
+        bindings: crate::config::BindingsConfig {
+            enabled: bindings_enabled,
+            ..crate::config::BindingsConfig::default()
+        },
+        generated: crate::config::GeneratedFiles::default(),
+    };
+
+    write_manifest(project_path, &project_config)
+}
+
+/// Rehashes each generated build file (normalized per `template_hashes.rs`)
+/// and records the result into the `[generated]` table of the manifest
+/// `write_initial_manifest` already wrote.
+fn record_generated_hashes(
+    project_path: &Path,
+    project_name: &str,
+    bundle_id: &str,
+    ctx: &crate::generators::ProjectContext,
+) -> Result<()> {
+    let manifest_path = project_path.join("oflike.toml");
+    let content = fs::read_to_string(&manifest_path)?;
+    let mut project_config: crate::config::ProjectConfig = toml::from_str(&content)
+        .map_err(|e| GeneratorError::Config(format!("{}: {}", manifest_path.display(), e)))?;
+
+    let subs = crate::template_hashes::Substitutions {
+        project_name,
+        bundle_id,
+        sdk_path: &ctx.xcode_env.sdk_path,
+        sdk_version: &ctx.xcode_env.sdk_version,
+        signing_identity: &ctx.signing.identity,
+    };
+
+    let mut hashes = std::collections::HashMap::new();
+    for file_name in ["CMakeLists.txt", "project.yml"] {
+        if let Ok(content) = fs::read_to_string(project_path.join(file_name)) {
+            let normalized = crate::template_hashes::normalize(&content, &subs);
+            hashes.insert(file_name.to_string(), crate::template_hashes::hash(&normalized));
+        }
+    }
+
+    project_config.generated = crate::config::GeneratedFiles {
+        hashes,
+        sdk_path: ctx.xcode_env.sdk_path.clone(),
+        sdk_version: ctx.xcode_env.sdk_version.clone(),
+        signing_identity: ctx.signing.identity.clone(),
+    };
+
+    write_manifest(project_path, &project_config)
+}
+
+fn write_manifest(project_path: &Path, project_config: &crate::config::ProjectConfig) -> Result<()> {
+    let manifest_content = toml::to_string_pretty(project_config)
+        .map_err(|e| GeneratorError::Config(format!("oflike.toml: {}", e)))?;
+    fs::write(project_path.join("oflike.toml"), manifest_content)?;
+    Ok(())
+}
+
 fn setup_addons(
     project_path: &Path,
     addon_list: &[String],
     addon_mode: &str,
     verbose: bool,
-) -> Result<()> {
+) -> Result<crate::addon_manifest::AddonDependencies> {
     let addons_dir = project_path.join("addons");
     fs::create_dir_all(&addons_dir)?;
 
     // Find oflike-metal root (assume it's in parent directories)
     let oflike_root = find_oflike_root()?;
 
+    let mut manifest_dirs = Vec::new();
+
     for addon in addon_list {
+        if !crate::utils::is_builtin_addon(addon) {
+            // `new --addons` only wires up the built-in Core/Native set;
+            // registry-resolved or vendored addons need project-level state
+            // (a lockfile entry, a resolved version) that doesn't exist yet
+            // for a project that's still being created.
+            return Err(GeneratorError::Other(format!(
+                "'{}' isn't a built-in Core/Native addon; add it with `oflike-gen add-addon {}` after creating the project",
+                addon, addon
+            )));
+        }
+
         let addon_category = if core_addons().contains(&addon.as_str()) {
             "core"
         } else {
@@ -379,6 +796,8 @@ fn setup_addons(
             continue;
         }
 
+        manifest_dirs.push(source_addon_path.clone());
+
         let dest_addon_path = addons_dir.join(addon);
 
         match addon_mode {
@@ -395,6 +814,7 @@ fn setup_addons(
                     println!("  Copying addon: {}", addon);
                 }
                 copy_dir_recursive(&source_addon_path, &dest_addon_path)?;
+                generate_addon_bindings(project_path, &dest_addon_path, addon, verbose)?;
             }
             "symlink" => {
                 // Create symlink
@@ -411,11 +831,89 @@ fn setup_addons(
                         "Symlinks are only supported on Unix systems".to_string(),
                     ));
                 }
+                generate_addon_bindings(project_path, &dest_addon_path, addon, verbose)?;
+            }
+            "vendor" => {
+                // Built-in core/apple_native addons ship inside the
+                // oflike-metal tree itself; vendoring (cloning a remote addon
+                // as a submodule) only makes sense for custom addons added
+                // afterwards via `add-addon --mode vendor --source <git-url>`.
+                return Err(GeneratorError::Other(format!(
+                    "'vendor' mode isn't supported for built-in addon '{}'; use `oflike-gen add-addon --mode vendor --source <git-url>` after project creation",
+                    addon
+                )));
             }
             _ => unreachable!(),
         }
     }
 
+    Ok(crate::addon_manifest::aggregate(&manifest_dirs))
+}
+
+/// If the project has a local `oflike.toml` with `[bindings] enabled = true`
+/// and the entry mode is `swiftui`, scan the addon's public headers and emit
+/// a bridging header (plus its `.mm` trampolines) and a Swift wrapper
+/// alongside the SwiftUI entry point, then wire the bridging header into the
+/// project's aggregator header so `SWIFT_OBJC_BRIDGING_HEADER` actually picks
+/// it up.
+pub(crate) fn generate_addon_bindings(
+    project_path: &Path,
+    addon_path: &Path,
+    addon_name: &str,
+    verbose: bool,
+) -> Result<()> {
+    let manifest_path = project_path.join("oflike.toml");
+    if !manifest_path.exists() {
+        return Ok(());
+    }
+
+    let content = fs::read_to_string(&manifest_path)?;
+    let manifest: crate::config::ProjectConfig = toml::from_str(&content)
+        .map_err(|e| GeneratorError::Config(format!("{}: {}", manifest_path.display(), e)))?;
+
+    if !manifest.bindings.enabled || manifest.entry.mode != "swiftui" {
+        return Ok(());
+    }
+
+    let src_headers = addon_path.join("src");
+    let headers_dir = if src_headers.exists() { &src_headers } else { addon_path };
+
+    let bridging_header = project_path
+        .join("src")
+        .join(format!("{}-Bridging.h", addon_name));
+    let swift_wrapper = project_path.join("src").join(format!("{}.swift", addon_name));
+
+    let generated = crate::bindings::generate_bindings(
+        headers_dir,
+        &manifest.bindings,
+        &bridging_header,
+        &swift_wrapper,
+    )?;
+
+    if generated {
+        generate_project_bridging_header(project_path)?;
+        reference_addon_bridging_header(project_path, addon_name)?;
+        if verbose {
+            println!("  Generated Swift bindings for addon: {}", addon_name);
+        }
+    }
+
+    Ok(())
+}
+
+/// Appends an `#include` for `<addon_name>-Bridging.h` to the project's
+/// aggregator bridging header (idempotent -- re-running `add-addon` or `new`
+/// shouldn't duplicate the line).
+fn reference_addon_bridging_header(project_path: &Path, addon_name: &str) -> Result<()> {
+    let path = project_path.join("src").join("Bridging.h");
+    let include_line = format!("#include \"{}-Bridging.h\"\n", addon_name);
+
+    let content = fs::read_to_string(&path)?;
+    if content.contains(&include_line) {
+        return Ok(());
+    }
+
+    fs::write(&path, content + &include_line)?;
     Ok(())
 }
 
@@ -470,7 +968,7 @@ fn expand_tilde(path: &str) -> PathBuf {
     PathBuf::from(path)
 }
 
-fn copy_dir_recursive(src: &Path, dst: &Path) -> Result<()> {
+pub(crate) fn copy_dir_recursive(src: &Path, dst: &Path) -> Result<()> {
     fs::create_dir_all(dst)?;
 
     for entry in fs::read_dir(src)? {
@@ -489,13 +987,31 @@ fn copy_dir_recursive(src: &Path, dst: &Path) -> Result<()> {
     Ok(())
 }
 
-fn generate_cmake_file(project_path: &Path, project_name: &str, addon_list: &[String]) -> Result<()> {
+pub(crate) fn generate_cmake_file(
+    project_path: &Path,
+    project_name: &str,
+    bundle_id: &str,
+    addon_list: &[String],
+    xcode_env: &crate::xcode::XcodeEnvironment,
+    signing: &crate::signing::ResolvedSigning,
+    extra_frameworks: &[String],
+    arch_mode: crate::arch::ArchMode,
+    project_type: crate::project_type::ProjectType,
+) -> Result<()> {
+    use crate::project_type::ProjectType;
+
+    // Match the XcodeGen backend's floor: target the oldest macOS we support,
+    // lowered to the installed SDK's version only if that SDK is older.
+    let deployment_target = crate::xcode::clamp_deployment_target("13.0", xcode_env);
+
     let mut cmake_content = format!(
         r#"cmake_minimum_required(VERSION 3.20)
 project({})
 
 set(CMAKE_CXX_STANDARD 20)
 set(CMAKE_CXX_STANDARD_REQUIRED ON)
+set(CMAKE_OSX_SYSROOT "{}")
+set(CMAKE_OSX_DEPLOYMENT_TARGET "{}")
 
 # Find oflike-metal framework
 find_package(oflike-metal REQUIRED)
@@ -505,9 +1021,16 @@ file(GLOB_RECURSE SOURCES "src/*.cpp" "src/*.mm")
 file(GLOB_RECURSE HEADERS "src/*.h")
 
 "#,
-        project_name
+        project_name, xcode_env.sdk_path, deployment_target
     );
 
+    if let Some(archs) = arch_mode.archs_value() {
+        cmake_content.push_str(&format!(
+            "set(CMAKE_OSX_ARCHITECTURES \"{}\")\n\n",
+            archs.replace(' ', ";")
+        ));
+    }
+
     cmake_content.push_str(
         r#"# Swift sources
 file(GLOB SWIFT_SOURCES "src/*.swift")
@@ -515,11 +1038,21 @@ file(GLOB SWIFT_SOURCES "src/*.swift")
 "#,
     );
 
-    cmake_content.push_str(&format!(
-        r#"# Executable
-add_executable({} MACOSX_BUNDLE ${{SOURCES}} ${{HEADERS}}"#,
-        project_name
-    ));
+    let target_decl = match project_type {
+        ProjectType::App => format!(
+            "# Executable\nadd_executable({} MACOSX_BUNDLE ${{SOURCES}} ${{HEADERS}}",
+            project_name
+        ),
+        ProjectType::Framework => format!(
+            "# Framework\nadd_library({} SHARED ${{SOURCES}} ${{HEADERS}}",
+            project_name
+        ),
+        ProjectType::StaticLib => format!(
+            "# Static library\nadd_library({} STATIC ${{SOURCES}} ${{HEADERS}}",
+            project_name
+        ),
+    };
+    cmake_content.push_str(&target_decl);
 
     cmake_content.push_str(" ${SWIFT_SOURCES}");
 
@@ -560,28 +1093,100 @@ target_include_directories({} PRIVATE addons/{})
         cmake_content.push('\n');
     }
 
-    // Bundle settings
-    cmake_content.push_str(&format!(
-        r#"# Bundle settings
+    match project_type {
+        ProjectType::App => {
+            cmake_content.push_str(&format!(
+                r#"# Bundle settings
 set_target_properties({} PROPERTIES
     MACOSX_BUNDLE_INFO_PLIST "${{CMAKE_CURRENT_SOURCE_DIR}}/resources/Info.plist"
     RESOURCE "${{CMAKE_CURRENT_SOURCE_DIR}}/resources;${{CMAKE_CURRENT_SOURCE_DIR}}/data"
+    XCODE_ATTRIBUTE_CODE_SIGN_IDENTITY "{}"
+    XCODE_ATTRIBUTE_ENABLE_HARDENED_RUNTIME "{}"
 )
 "#,
-        project_name
-    ));
+                project_name,
+                signing.identity,
+                if signing.hardened_runtime { "YES" } else { "NO" }
+            ));
+
+            if let Some(entitlements_path) = &signing.entitlements_path {
+                cmake_content.push_str(&format!(
+                    "set_target_properties({} PROPERTIES XCODE_ATTRIBUTE_CODE_SIGN_ENTITLEMENTS \"${{CMAKE_CURRENT_SOURCE_DIR}}/{}\")\n",
+                    project_name, entitlements_path
+                ));
+            }
+        }
+        ProjectType::Framework => {
+            cmake_content.push_str(&format!(
+                r#"# Framework settings
+set_target_properties({0} PROPERTIES
+    FRAMEWORK TRUE
+    FRAMEWORK_VERSION A
+    MACOSX_FRAMEWORK_IDENTIFIER "{1}"
+    MACOSX_FRAMEWORK_INFO_PLIST "${{CMAKE_CURRENT_SOURCE_DIR}}/resources/Info.plist"
+    PUBLIC_HEADER "${{CMAKE_CURRENT_SOURCE_DIR}}/include/{0}.h"
+)
+"#,
+                project_name, bundle_id
+            ));
+        }
+        ProjectType::StaticLib => {
+            // Static libraries aren't signed or bundled; nothing to set here.
+        }
+    }
+
+    // Addon-declared system frameworks
+    if !extra_frameworks.is_empty() {
+        cmake_content.push_str("\n# Addon-declared frameworks\n");
+        for framework in extra_frameworks {
+            cmake_content.push_str(&format!(
+                "target_link_libraries({} \"-framework {}\")\n",
+                project_name, framework
+            ));
+        }
+    }
 
     fs::write(project_path.join("CMakeLists.txt"), cmake_content)?;
 
     Ok(())
 }
 
-fn generate_xcodegen_file(
+pub(crate) fn generate_xcodegen_file(
     project_path: &Path,
     project_name: &str,
     bundle_id: &str,
     addon_list: &[String],
+    xcode_env: &crate::xcode::XcodeEnvironment,
+    signing: &crate::signing::ResolvedSigning,
+    extra_frameworks: &[String],
+    arch_mode: crate::arch::ArchMode,
+    project_type: crate::project_type::ProjectType,
 ) -> Result<()> {
+    use crate::project_type::ProjectType;
+
+    let deployment_target = crate::xcode::clamp_deployment_target("13.0", xcode_env);
+    let arch_settings_yaml = match arch_mode.archs_value() {
+        Some(archs) => format!(
+            "      ARCHS: \"{}\"\n      ONLY_ACTIVE_ARCH: NO\n",
+            archs
+        ),
+        None => String::new(),
+    };
+    let target_type_yaml = match project_type {
+        ProjectType::App => "application",
+        ProjectType::Framework => "framework",
+        ProjectType::StaticLib => "library.static",
+    };
+    let type_settings_yaml = match project_type {
+        ProjectType::Framework => concat!(
+            "      INSTALL_PATH: \"$(LOCAL_LIBRARY_DIR)/Frameworks\"\n",
+            "      SKIP_INSTALL: \"NO\"\n",
+            "      DEFINES_MODULE: \"YES\"\n",
+            "      MODULEMAP_FILE: \"$(PROJECT_DIR)/include/module.modulemap\"\n",
+        )
+        .to_string(),
+        _ => String::new(),
+    };
     let oflike_root = find_oflike_root().ok();
     let static_lib_dir = oflike_root
         .as_ref()
@@ -595,17 +1200,23 @@ fn generate_xcodegen_file(
         String::new()
     };
 
-    let dependencies_yaml = if static_lib_dir.is_some() {
+    let mut dependencies_yaml = if static_lib_dir.is_some() {
         "    dependencies:\n      - sdk: Cocoa.framework\n      - sdk: Metal.framework\n      - sdk: MetalKit.framework\n      - sdk: QuartzCore.framework\n      - sdk: CoreGraphics.framework\n      - sdk: CoreText.framework\n      - sdk: ImageIO.framework\n      - sdk: Accelerate.framework\n"
+            .to_string()
     } else {
-        "    dependencies:\n      - framework: oflike-metal.framework\n        embed: true\n"
+        "    dependencies:\n      - framework: oflike-metal.framework\n        embed: true\n".to_string()
     };
+    for framework in extra_frameworks {
+        dependencies_yaml.push_str(&format!("      - sdk: {}.framework\n", framework));
+    }
 
-    let mut sources_list = vec![
-        "src".to_string(),
-        "../../shaders/Basic2D.metal".to_string(),
-        "../../shaders/Basic3D.metal".to_string(),
-    ];
+    // A static lib has no app bundle to carry shaders/data/resources into --
+    // those only make sense for a running target (App/Framework).
+    let mut sources_list = vec!["src".to_string()];
+    if project_type != ProjectType::StaticLib {
+        sources_list.push("../../shaders/Basic2D.metal".to_string());
+        sources_list.push("../../shaders/Basic3D.metal".to_string());
+    }
     if !addon_list.is_empty() {
         for addon in addon_list {
             sources_list.push(format!("addons/{}", addon));
@@ -616,42 +1227,54 @@ fn generate_xcodegen_file(
         .iter()
         .map(|s| format!("      - {}", s))
         .collect::<Vec<_>>();
-    sources_yaml_lines.push("      - path: data".to_string());
-    sources_yaml_lines.push("        type: folder".to_string());
-    sources_yaml_lines.push("        buildPhase: resources".to_string());
-    sources_yaml_lines.push("      - path: resources".to_string());
-    sources_yaml_lines.push("        type: folder".to_string());
-    sources_yaml_lines.push("        buildPhase: resources".to_string());
+    if project_type != ProjectType::StaticLib {
+        sources_yaml_lines.push("      - path: data".to_string());
+        sources_yaml_lines.push("        type: folder".to_string());
+        sources_yaml_lines.push("        buildPhase: resources".to_string());
+        sources_yaml_lines.push("      - path: resources".to_string());
+        sources_yaml_lines.push("        type: folder".to_string());
+        sources_yaml_lines.push("        buildPhase: resources".to_string());
+    }
     let sources_yaml = sources_yaml_lines.join("\n");
 
+    // `generate_info_plist` only ever runs for App/Framework (see
+    // `generate_template_files`), so a static lib has no Info.plist to point
+    // at.
+    let infoplist_settings_yaml = if project_type == ProjectType::StaticLib {
+        String::new()
+    } else {
+        "      INFOPLIST_FILE: resources/Info.plist\n".to_string()
+    };
+
     let xcodegen_content = format!(
         r#"name: {}
 options:
   bundleIdPrefix: {}
   deploymentTarget:
-    macOS: "13.0"
+    macOS: "{}"
 
 targets:
   {}:
-    type: application
+    type: {}
     platform: macOS
     sources:
 {}
     settings:
       PRODUCT_BUNDLE_IDENTIFIER: {}
-      INFOPLIST_FILE: resources/Info.plist
-      SWIFT_VERSION: "5.9"
+{}      SWIFT_VERSION: "5.9"
       MTL_ENABLE_DEBUG_INFO: "NO"
       MTL_COMPILER_FLAGS: "-fmodules-cache-path=/tmp/oflike_metal_module_cache"
-      SWIFT_OBJC_BRIDGING_HEADER: "$(PROJECT_DIR)/../../src/platform/bridge/oflike-metal-Bridging-Header.h"
+      SWIFT_OBJC_BRIDGING_HEADER: "$(PROJECT_DIR)/src/Bridging.h"
       CLANG_CXX_LANGUAGE_STANDARD: "c++20"
       CLANG_CXX_LIBRARY: "libc++"
-      ENABLE_HARDENED_RUNTIME: NO
-      CODE_SIGNING_ALLOWED: NO
-      CODE_SIGNING_REQUIRED: NO
-      CODE_SIGN_IDENTITY: ""
+      ENABLE_HARDENED_RUNTIME: {}
+      CODE_SIGNING_ALLOWED: {}
+      CODE_SIGNING_REQUIRED: {}
+      CODE_SIGN_IDENTITY: "{}"
+{}{}{}      SDKROOT: "{}"
       HEADER_SEARCH_PATHS:
         - "$(PROJECT_DIR)/../../src"
+        - "{}/System/Library/Frameworks"
 {}
 {}
     scheme:
@@ -659,9 +1282,24 @@ targets:
 "#,
         project_name,
         bundle_id,
+        deployment_target,
         project_name,
+        target_type_yaml,
         sources_yaml,
         bundle_id,
+        infoplist_settings_yaml,
+        if signing.hardened_runtime { "YES" } else { "NO" },
+        if signing.identity.is_empty() { "NO" } else { "YES" },
+        if signing.identity.is_empty() { "NO" } else { "YES" },
+        signing.identity,
+        match &signing.entitlements_path {
+            Some(path) => format!("      CODE_SIGN_ENTITLEMENTS: {}\n", path),
+            None => String::new(),
+        },
+        arch_settings_yaml,
+        type_settings_yaml,
+        xcode_env.sdk_path,
+        xcode_env.sdk_path,
         library_settings_yaml,
         dependencies_yaml
     );
@@ -671,47 +1309,72 @@ targets:
     Ok(())
 }
 
-fn generate_gitignore(project_path: &Path) -> Result<()> {
-    let gitignore_content = r#"# Xcode
-*.xcodeproj
-*.xcworkspace
-!default.xcworkspace
-*.pbxuser
-*.mode1v3
-*.mode2v3
-*.perspectivev3
-xcuserdata/
-DerivedData/
-*.xccheckout
-*.moved-aside
-*.hmap
-*.ipa
-
-# CMake
-build/
-CMakeCache.txt
-CMakeFiles/
-cmake_install.cmake
-*.cmake
-
-# macOS
-.DS_Store
-.AppleDouble
-.LSOverride
-Icon
-._*
-
-# Temporary
-*.swp
-*.swo
-*~
-
-# Data
-data/*
-!data/.gitkeep
-"#;
-
-    fs::write(project_path.join(".gitignore"), gitignore_content)?;
+/// Glob patterns common to every ignore file we generate, without any
+/// git-specific `!negation` syntax Mercurial's `ignore-glob`-equivalent
+/// backends can't express.
+const IGNORE_GLOBS: &[&str] = &[
+    "*.xcodeproj",
+    "*.xcworkspace",
+    "*.pbxuser",
+    "*.mode1v3",
+    "*.mode2v3",
+    "*.perspectivev3",
+    "xcuserdata/",
+    "DerivedData/",
+    "*.xccheckout",
+    "*.moved-aside",
+    "*.hmap",
+    "*.ipa",
+    "build/",
+    "CMakeCache.txt",
+    "CMakeFiles/",
+    "cmake_install.cmake",
+    "*.cmake",
+    ".DS_Store",
+    ".AppleDouble",
+    ".LSOverride",
+    "Icon",
+    "._*",
+    "*.swp",
+    "*.swo",
+    "*~",
+];
+
+fn generate_ignore_file(project_path: &Path, vcs: crate::vcs::VersionControl) -> Result<()> {
+    let Some(ignore_file_name) = vcs.ignore_file_name() else {
+        return Ok(());
+    };
+
+    let ignore_content = match vcs {
+        // Git and Pijul both read their ignore file as gitignore-style
+        // globs, including `!negation`.
+        crate::vcs::VersionControl::Git | crate::vcs::VersionControl::Pijul => {
+            format!(
+                "{}\n\n# Data\ndata/*\n!data/.gitkeep\n",
+                IGNORE_GLOBS.join("\n")
+            )
+        }
+        // `.hgignore` defaults to Python regexp syntax; without this header
+        // every glob above (e.g. `*.swp`, `build/`) would be parsed as a
+        // regexp instead and mismatch almost everything it's meant to match.
+        // Mercurial also has no negation, so the data dir is excluded
+        // wholesale rather than kept-with-a-gitkeep-exception.
+        crate::vcs::VersionControl::Hg => {
+            format!("syntax: glob\n\n{}\n\n# Data\ndata/\n", IGNORE_GLOBS.join("\n"))
+        }
+        // Fossil's ignore-glob is a flat, newline-separated glob list with no
+        // `!negation` support either.
+        crate::vcs::VersionControl::Fossil => {
+            format!("{}\ndata/\n", IGNORE_GLOBS.join("\n"))
+        }
+        crate::vcs::VersionControl::None => return Ok(()),
+    };
+
+    let ignore_path = project_path.join(ignore_file_name);
+    if let Some(parent) = ignore_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(ignore_path, ignore_content)?;
 
     Ok(())
 }
@@ -797,26 +1460,7 @@ MIT
     Ok(())
 }
 
-fn init_git(project_path: &Path, verbose: bool) -> Result<()> {
-    if verbose {
-        println!("Initializing git repository...");
-    }
-
-    let output = std::process::Command::new("git")
-        .arg("init")
-        .current_dir(project_path)
-        .output()?;
-
-    if !output.status.success() {
-        return Err(GeneratorError::Other(
-            "Failed to initialize git repository".to_string(),
-        ));
-    }
-
-    Ok(())
-}
-
-fn run_xcodegen(project_path: &Path, verbose: bool) -> Result<()> {
+pub(crate) fn run_xcodegen(project_path: &Path, verbose: bool) -> Result<()> {
     if verbose {
         println!("Running XcodeGen...");
     }
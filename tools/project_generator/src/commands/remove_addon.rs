@@ -1,4 +1,6 @@
 use crate::error::Result;
+use crate::lockfile::LockFile;
+use std::path::Path;
 
 pub fn execute(
     addon_name: &str,
@@ -12,6 +14,16 @@ pub fn execute(
         println!("  Keep files: {}", keep_files);
     }
 
+    let project_path = Path::new(project);
+    let mut lock = LockFile::load_or_default(project_path)?;
+    if let Some((namespace, id)) = addon_name.split_once('/') {
+        lock.remove(namespace, id);
+    } else {
+        lock.remove_vendored(addon_name);
+    }
+    lock.save(project_path)?;
+    crate::compile_db::refresh(project_path)?;
+
     println!("✅ Addon '{}' removed successfully!", addon_name);
     println!("   Note: This is a placeholder. Full implementation in Phase 10.3");
 
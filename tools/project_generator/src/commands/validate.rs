@@ -1,4 +1,8 @@
+use crate::config::ProjectConfig;
 use crate::error::Result;
+use crate::lockfile::LockFile;
+use crate::template_hashes::{self, Substitutions};
+use std::fs;
 use std::path::Path;
 
 pub fn execute(project: &str, fix: bool, verbose: bool) -> Result<()> {
@@ -34,10 +38,124 @@ pub fn execute(project: &str, fix: bool, verbose: bool) -> Result<()> {
     } else {
         println!();
         println!("⚠️  Project structure has issues");
+    }
+
+    check_template_drift(project_path, fix, verbose)?;
+    check_lockfile_drift(project_path, verbose)?;
+
+    Ok(())
+}
+
+/// Compares `oflike.lock` against the registry-resolved custom addons
+/// declared in `oflike.toml`, surfacing drift in either direction via
+/// `LockFile::staleness` (e.g. an addon added to `oflike.toml` by hand but
+/// never locked with `add-addon`, or one removed from `oflike.toml` whose
+/// lock entry was never cleaned up).
+fn check_lockfile_drift(project_path: &Path, verbose: bool) -> Result<()> {
+    let manifest_path = project_path.join("oflike.toml");
+    let Ok(manifest_content) = fs::read_to_string(&manifest_path) else {
+        if verbose {
+            println!();
+            println!("  (no oflike.toml found, skipping lockfile drift check)");
+        }
+        return Ok(());
+    };
+
+    let config: ProjectConfig = toml::from_str(&manifest_content).map_err(|e| {
+        crate::error::GeneratorError::Config(format!("{}: {}", manifest_path.display(), e))
+    })?;
+
+    let Some(lock) = LockFile::load(project_path)? else {
+        if verbose {
+            println!();
+            println!("  (no oflike.lock found, skipping lockfile drift check)");
+        }
+        return Ok(());
+    };
+
+    let declared: Vec<String> = config
+        .addons
+        .custom
+        .iter()
+        .filter(|c| c.mode == "registry")
+        .map(|c| c.name.clone())
+        .collect();
+    let warnings = lock.staleness(&declared);
+
+    println!();
+    if warnings.is_empty() {
+        println!("✅ oflike.lock matches the custom addons declared in oflike.toml");
+    } else {
+        println!("⚠️  oflike.lock is out of sync with oflike.toml:");
+        for warning in &warnings {
+            println!("   {}", warning);
+        }
+    }
+
+    Ok(())
+}
+
+/// Compares `CMakeLists.txt` against the known template hashes in
+/// `template_hashes.rs`, using the substitutions recorded in `oflike.toml`'s
+/// `[generated]` table at generation time (see `commands::new`). Projects
+/// created before that table existed have nothing to check here.
+fn check_template_drift(project_path: &Path, fix: bool, verbose: bool) -> Result<()> {
+    let manifest_path = project_path.join("oflike.toml");
+    let Ok(manifest_content) = fs::read_to_string(&manifest_path) else {
+        if verbose {
+            println!();
+            println!("  (no oflike.toml found, skipping template-drift check)");
+        }
+        return Ok(());
+    };
+
+    let config: ProjectConfig = toml::from_str(&manifest_content).map_err(|e| {
+        crate::error::GeneratorError::Config(format!("{}: {}", manifest_path.display(), e))
+    })?;
+
+    let Some(recorded_hash) = config.generated.hashes.get("CMakeLists.txt") else {
+        return Ok(());
+    };
+
+    let cmake_path = project_path.join("CMakeLists.txt");
+    let Ok(content) = fs::read_to_string(&cmake_path) else {
+        return Ok(());
+    };
+
+    let subs = Substitutions {
+        project_name: &config.project.name,
+        bundle_id: &config.project.bundle_id,
+        sdk_path: &config.generated.sdk_path,
+        sdk_version: &config.generated.sdk_version,
+        signing_identity: &config.generated.signing_identity,
+    };
+    let current_hash = template_hashes::hash(&template_hashes::normalize(&content, &subs));
+
+    println!();
+    if template_hashes::is_known_cmake_template(&current_hash) {
+        if current_hash == *recorded_hash {
+            println!("✅ CMakeLists.txt matches the template it was generated from");
+        } else {
+            println!("✅ CMakeLists.txt is untouched boilerplate from an older template version");
+            if fix {
+                println!("   Nothing to regenerate yet -- no newer template is known.");
+            }
+        }
+    } else {
+        println!("⚠️  CMakeLists.txt no longer matches any known template");
+        println!("   It looks like this file has been hand-edited since it was generated.");
         if fix {
-            println!("   (auto-fix not yet implemented)");
+            println!(
+                "   Refusing to overwrite it; review it by hand against a fresh `oflike-gen new`."
+            );
         }
     }
 
+    if config.generated.hashes.contains_key("project.yml") {
+        println!(
+            "ℹ️  project.yml's content depends on the local build environment and can't be verified against a known template."
+        );
+    }
+
     Ok(())
 }
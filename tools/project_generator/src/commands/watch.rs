@@ -0,0 +1,405 @@
+//! `watch` turns oflike-gen into a fast edit-compile-run loop: it watches a
+//! generated project's sources and build manifests, and on change
+//! regenerates (`xcodegen generate`, only when `project.yml` changed),
+//! rebuilds (`xcodebuild`, streamed line-by-line instead of buffered like
+//! `run_xcodegen`), and optionally relaunches the built `.app` — without
+//! needing Xcode open.
+
+use crate::error::{GeneratorError, Result};
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc, Condvar, Mutex};
+use std::time::{Duration, Instant};
+
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// The latest pending build request, coalesced so only one is ever queued.
+#[derive(Clone, Copy)]
+struct BuildRequest {
+    regenerate: bool,
+}
+
+pub fn execute(project: &str, configuration: &str, launch: bool, verbose: bool) -> Result<()> {
+    let project_path = Path::new(project)
+        .canonicalize()
+        .map_err(|_| GeneratorError::ProjectNotFound(project.to_string()))?;
+    let project_name = read_project_name(&project_path)?;
+
+    println!(
+        "👀 Watching '{}' (scheme: {}, configuration: {})",
+        project_path.display(),
+        project_name,
+        configuration
+    );
+
+    let active_child: Arc<Mutex<Option<u32>>> = Arc::new(Mutex::new(None));
+    let queued: Arc<(Mutex<Option<BuildRequest>>, Condvar)> =
+        Arc::new((Mutex::new(None), Condvar::new()));
+    let building = Arc::new(AtomicBool::new(false));
+
+    spawn_builder_thread(
+        project_path.clone(),
+        project_name.clone(),
+        configuration.to_string(),
+        launch,
+        verbose,
+        active_child.clone(),
+        queued.clone(),
+        building,
+    );
+
+    // Kick off an initial build before watching for changes.
+    submit(&queued, BuildRequest { regenerate: true });
+
+    let watched_paths = [
+        project_path.join("src"),
+        project_path.join("data"),
+        project_path.join("resources"),
+        project_path.join("project.yml"),
+        project_path.join("CMakeLists.txt"),
+    ];
+
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+        if let Ok(event) = res {
+            let _ = tx.send(event);
+        }
+    })
+    .map_err(|e| GeneratorError::Other(format!("Failed to start file watcher: {}", e)))?;
+
+    for path in &watched_paths {
+        if !path.exists() {
+            continue;
+        }
+        let mode = if path.is_dir() {
+            notify::RecursiveMode::Recursive
+        } else {
+            notify::RecursiveMode::NonRecursive
+        };
+        watcher
+            .watch(path, mode)
+            .map_err(|e| GeneratorError::Other(format!("Failed to watch {}: {}", path.display(), e)))?;
+    }
+
+    let mut pending_regenerate = false;
+    let mut last_event_at: Option<Instant> = None;
+
+    loop {
+        let timeout = match last_event_at {
+            Some(at) => DEBOUNCE
+                .saturating_sub(at.elapsed())
+                .max(Duration::from_millis(1)),
+            None => Duration::from_secs(3600),
+        };
+
+        match rx.recv_timeout(timeout) {
+            Ok(event) => {
+                if event
+                    .paths
+                    .iter()
+                    .any(|p| p.file_name().map(|n| n == "project.yml").unwrap_or(false))
+                {
+                    pending_regenerate = true;
+                }
+                last_event_at = Some(Instant::now());
+            }
+            Err(mpsc::RecvTimeoutError::Timeout) => {
+                if let Some(at) = last_event_at {
+                    if at.elapsed() >= DEBOUNCE {
+                        last_event_at = None;
+                        if verbose {
+                            println!("  change detected, rebuilding...");
+                        }
+                        // Cancel whatever is mid-build so the coalesced
+                        // request below is the one that actually runs.
+                        if let Some(pid) = *active_child.lock().unwrap() {
+                            let _ = Command::new("kill").args(["-9", &pid.to_string()]).output();
+                        }
+                        submit(
+                            &queued,
+                            BuildRequest {
+                                regenerate: pending_regenerate,
+                            },
+                        );
+                        pending_regenerate = false;
+                    }
+                }
+            }
+            Err(mpsc::RecvTimeoutError::Disconnected) => break,
+        }
+    }
+
+    Ok(())
+}
+
+fn submit(queued: &Arc<(Mutex<Option<BuildRequest>>, Condvar)>, request: BuildRequest) {
+    let (lock, cvar) = &**queued;
+    let mut slot = lock.lock().unwrap();
+    *slot = match *slot {
+        Some(existing) => Some(BuildRequest {
+            regenerate: existing.regenerate || request.regenerate,
+        }),
+        None => Some(request),
+    };
+    cvar.notify_one();
+}
+
+#[allow(clippy::too_many_arguments)]
+fn spawn_builder_thread(
+    project_path: PathBuf,
+    project_name: String,
+    configuration: String,
+    launch: bool,
+    verbose: bool,
+    active_child: Arc<Mutex<Option<u32>>>,
+    queued: Arc<(Mutex<Option<BuildRequest>>, Condvar)>,
+    building: Arc<AtomicBool>,
+) {
+    std::thread::spawn(move || loop {
+        let request = {
+            let (lock, cvar) = &*queued;
+            let mut slot = lock.lock().unwrap();
+            while slot.is_none() {
+                slot = cvar.wait(slot).unwrap();
+            }
+            slot.take().unwrap()
+        };
+
+        building.store(true, Ordering::SeqCst);
+
+        if request.regenerate {
+            println!("  regenerating Xcode project (project.yml changed)...");
+            match run_streaming(
+                Command::new("xcodegen").arg("generate").current_dir(&project_path),
+                &active_child,
+            ) {
+                Ok(true) => {}
+                Ok(false) => {
+                    // Failed, or killed by a newer change: don't go on to
+                    // build with a stale project.
+                    if verbose {
+                        println!("  xcodegen cancelled or failed, skipping build");
+                    }
+                    building.store(false, Ordering::SeqCst);
+                    continue;
+                }
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    building.store(false, Ordering::SeqCst);
+                    continue;
+                }
+            }
+        }
+
+        let mut build_cmd = Command::new("xcodebuild");
+        build_cmd
+            .args([
+                "-project",
+                &format!("{}.xcodeproj", project_name),
+                "-scheme",
+                &project_name,
+                "-configuration",
+                &configuration,
+                "-destination",
+                "platform=macOS",
+                "-derivedDataPath",
+                &format!("build/DerivedData/{}", project_name),
+                "build",
+            ])
+            .current_dir(&project_path)
+            .env(
+                "MTL_COMPILER_FLAGS",
+                "-fmodules-cache-path=/tmp/oflike_metal_module_cache",
+            );
+
+        match run_streaming(&mut build_cmd, &active_child) {
+            Ok(true) => {
+                println!("✅ Build succeeded");
+                if let Err(e) = verify_build_archs(&project_path, &project_name, &configuration) {
+                    eprintln!("Warning: {}", e);
+                }
+                if launch {
+                    if let Err(e) = relaunch_app(&project_path, &project_name, &configuration) {
+                        eprintln!("Warning: failed to launch app: {}", e);
+                    }
+                }
+            }
+            Ok(false) => {
+                if verbose {
+                    println!("  build cancelled or failed (superseded by a newer change)");
+                }
+            }
+            Err(e) => eprintln!("Error: {}", e),
+        }
+        building.store(false, Ordering::SeqCst);
+    });
+}
+
+/// Reads the `name:` key out of `project.yml` with a line scan, matching the
+/// rest of the generator's avoidance of a full YAML parser for this tool.
+fn read_project_name(project_path: &Path) -> Result<String> {
+    let content = std::fs::read_to_string(project_path.join("project.yml")).map_err(|_| {
+        GeneratorError::Other(format!(
+            "'{}' has no project.yml (run `oflike-gen new` first)",
+            project_path.display()
+        ))
+    })?;
+
+    content
+        .lines()
+        .find_map(|line| line.strip_prefix("name:"))
+        .map(|name| name.trim().to_string())
+        .ok_or_else(|| GeneratorError::Other("project.yml has no 'name:' key".to_string()))
+}
+
+/// Reads the `ARCHS:` key out of `project.yml`, the same naive way
+/// `read_project_name` reads `name:`. Absent key (native builds don't emit
+/// one) means there's nothing to verify.
+fn read_required_slices(project_path: &Path) -> Vec<String> {
+    let Ok(content) = std::fs::read_to_string(project_path.join("project.yml")) else {
+        return Vec::new();
+    };
+
+    content
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("ARCHS:"))
+        .map(|value| {
+            value
+                .trim()
+                .trim_matches('"')
+                .split(' ')
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Runs the `lipo`-based slice check from [`crate::arch`] against the just-
+/// built app binary and any bundled static libs, after a successful build.
+fn verify_build_archs(project_path: &Path, project_name: &str, configuration: &str) -> Result<()> {
+    let required_slices = read_required_slices(project_path);
+    if required_slices.is_empty() {
+        return Ok(());
+    }
+    let required_slices: Vec<&str> = required_slices.iter().map(String::as_str).collect();
+
+    let app_binary = project_path
+        .join("build/DerivedData")
+        .join(project_name)
+        .join(format!(
+            "Build/Products/{}/{}.app/Contents/MacOS/{}",
+            configuration, project_name, project_name
+        ));
+
+    let static_libs = find_oflike_root(project_path)
+        .map(|root| {
+            vec![
+                root.join("build/liboflike-metal.a"),
+                root.join("build/third_party/libtess2.a"),
+                root.join("build/third_party/liboscpack.a"),
+                root.join("build/third_party/libpugixml.a"),
+            ]
+        })
+        .unwrap_or_default();
+
+    crate::arch::verify_build(&app_binary, &static_libs, &required_slices)
+}
+
+/// Walks up from `start` looking for the oflike-metal root (an `addons/`
+/// directory with `core`/`apple_native` subdirectories), same heuristic
+/// `new.rs`'s `find_oflike_root` uses from the current directory.
+fn find_oflike_root(start: &Path) -> Option<PathBuf> {
+    let mut current = start.to_path_buf();
+    loop {
+        let addons_path = current.join("addons");
+        if addons_path.exists()
+            && (addons_path.join("core").exists() || addons_path.join("apple_native").exists())
+        {
+            return Some(current);
+        }
+        if !current.pop() {
+            return None;
+        }
+    }
+}
+
+/// Spawns `cmd` with piped stdout/stderr, streams both line-by-line as they
+/// arrive, and tracks the child's pid in `active_child` so a new file change
+/// can kill it mid-build. Returns `Ok(false)` if the process failed or was
+/// killed (we can't tell a cancellation apart from a genuine build failure
+/// once the child is gone, so callers treat both as "no relaunch").
+fn run_streaming(cmd: &mut Command, active_child: &Arc<Mutex<Option<u32>>>) -> Result<bool> {
+    let mut child = cmd
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| GeneratorError::Other(format!("Failed to run {:?}: {}", cmd, e)))?;
+
+    let stdout = child.stdout.take();
+    let stderr = child.stderr.take();
+
+    let stdout_thread = stdout.map(|s| {
+        std::thread::spawn(move || {
+            for line in BufReader::new(s).lines().map_while(std::result::Result::ok) {
+                println!("{}", line);
+            }
+        })
+    });
+    let stderr_thread = stderr.map(|s| {
+        std::thread::spawn(move || {
+            for line in BufReader::new(s).lines().map_while(std::result::Result::ok) {
+                eprintln!("{}", line);
+            }
+        })
+    });
+
+    *active_child.lock().unwrap() = Some(child.id());
+    // Wait on our own owned `Child` handle, not through the mutex: holding
+    // the lock across `wait()` would block a mid-build cancellation (which
+    // also locks `active_child`) until the build finished on its own.
+    let status = child.wait();
+    *active_child.lock().unwrap() = None;
+
+    if let Some(t) = stdout_thread {
+        let _ = t.join();
+    }
+    if let Some(t) = stderr_thread {
+        let _ = t.join();
+    }
+
+    match status {
+        Ok(status) => Ok(status.success()),
+        Err(e) => Err(GeneratorError::Other(format!("Build process error: {}", e))),
+    }
+}
+
+fn relaunch_app(project_path: &Path, project_name: &str, configuration: &str) -> Result<()> {
+    let app_path = project_path
+        .join("build/DerivedData")
+        .join(project_name)
+        .join(format!(
+            "Build/Products/{}/{}.app",
+            configuration, project_name
+        ));
+
+    if !app_path.exists() {
+        return Err(GeneratorError::Other(format!(
+            "Built app not found at {}",
+            app_path.display()
+        )));
+    }
+
+    let _ = Command::new("pkill")
+        .args(["-f", &app_path.display().to_string()])
+        .output();
+
+    Command::new("open")
+        .arg(&app_path)
+        .output()
+        .map_err(|e| GeneratorError::Other(format!("Failed to launch app: {}", e)))?;
+
+    println!("🚀 Launched {}", app_path.display());
+    Ok(())
+}
@@ -0,0 +1,186 @@
+//! Manages a collection of oflike-metal sketches under one workspace root:
+//! enumerating members, adding/removing them, and re-syncing shared build
+//! settings and addons across all of them.
+
+use crate::config::{ProjectConfig, WorkspaceConfig};
+use crate::error::Result;
+use std::fs;
+use std::path::Path;
+
+pub fn init(root: &Path, verbose: bool) -> Result<()> {
+    let path = root.join(crate::config::WORKSPACE_FILE_NAME);
+    if path.exists() {
+        return Err(crate::error::GeneratorError::ProjectExists(
+            path.display().to_string(),
+        ));
+    }
+    WorkspaceConfig::default().save(root)?;
+    if verbose {
+        println!("Initialized workspace at {}", path.display());
+    }
+    println!("✅ Workspace created: {}", path.display());
+    Ok(())
+}
+
+pub fn add_member(root: &Path, member_path: &str, verbose: bool) -> Result<()> {
+    let mut ws = WorkspaceConfig::load(root)?;
+    if ws.members.iter().any(|m| m == member_path) {
+        println!("'{}' is already a workspace member", member_path);
+        return Ok(());
+    }
+    ws.members.push(member_path.to_string());
+    ws.save(root)?;
+    if verbose {
+        println!("Added member: {}", member_path);
+    }
+    println!("✅ Added '{}' to workspace", member_path);
+    Ok(())
+}
+
+pub fn remove_member(root: &Path, member_path: &str, verbose: bool) -> Result<()> {
+    let mut ws = WorkspaceConfig::load(root)?;
+    ws.members.retain(|m| m != member_path);
+    ws.save(root)?;
+    if verbose {
+        println!("Removed member: {}", member_path);
+    }
+    println!("✅ Removed '{}' from workspace", member_path);
+    Ok(())
+}
+
+/// Lists every member, and (in workspace-aware mode) which addons each one
+/// references.
+pub fn list(root: &Path, verbose: bool) -> Result<()> {
+    let ws = WorkspaceConfig::load(root)?;
+    println!("Workspace members:");
+    for member in &ws.members {
+        match read_member_config(root, member) {
+            Some(project) => {
+                let addons = member_addon_names(&project).join(", ");
+                println!("  - {} (addons: {})", member, addons);
+            }
+            None => println!("  - {} (no oflike.toml)", member),
+        }
+    }
+    if verbose {
+        println!("Root: {}", root.display());
+    }
+    Ok(())
+}
+
+/// Re-applies `shared_build` and `shared_addons` across every member's
+/// `oflike.toml`, regenerating `CMakeLists.txt`/`project.yml` wherever the
+/// member's build files drift from the freshly-synced settings.
+pub fn sync(root: &Path, verbose: bool) -> Result<()> {
+    let ws = WorkspaceConfig::load(root)?;
+    let xcode_env = crate::xcode::probe()?;
+
+    for member in &ws.members {
+        let member_path = root.join(member);
+        let manifest_path = member_path.join("oflike.toml");
+        let Some(mut project) = read_member_config(root, member) else {
+            eprintln!("Warning: '{}' has no oflike.toml, skipping", member);
+            continue;
+        };
+
+        project.build.apply_override(&ws.shared_build);
+
+        for addon in &ws.shared_addons.core {
+            if !project.addons.core.contains(addon) {
+                project.addons.core.push(addon.clone());
+            }
+        }
+        for addon in &ws.shared_addons.custom {
+            if !project.addons.custom.iter().any(|c| c.name == addon.name) {
+                project.addons.custom.push(addon.clone());
+            }
+        }
+
+        let rendered = toml::to_string_pretty(&project).map_err(|e| {
+            crate::error::GeneratorError::Config(format!("{}: {}", manifest_path.display(), e))
+        })?;
+        fs::write(&manifest_path, rendered)?;
+
+        let addon_names = member_addon_names(&project);
+        let before_cmake = fs::read_to_string(member_path.join("CMakeLists.txt")).ok();
+        let before_yml = fs::read_to_string(member_path.join("project.yml")).ok();
+
+        // Workspace members have no per-project signing config yet, so sync
+        // regenerates build files unsigned, same as before this was added.
+        let signing = crate::signing::ResolvedSigning {
+            identity: String::new(),
+            hardened_runtime: false,
+            entitlements_path: None,
+        };
+
+        // Workspace sync doesn't aggregate per-addon manifests yet, so no
+        // extra frameworks are threaded through here.
+        let extra_frameworks: Vec<String> = Vec::new();
+
+        // Workspace members have no per-project arch config yet, so sync
+        // always regenerates build files for the host architecture.
+        let arch_mode = crate::arch::ArchMode::Native;
+
+        // Workspace members are always plain apps; framework/static-lib
+        // members aren't tracked by the workspace manifest yet.
+        let project_type = crate::project_type::ProjectType::App;
+
+        if project.build.cmake {
+            super::new::generate_cmake_file(
+                &member_path,
+                &project.project.name,
+                &project.project.bundle_id,
+                &addon_names,
+                &xcode_env,
+                &signing,
+                &extra_frameworks,
+                arch_mode,
+                project_type,
+            )?;
+        }
+        if project.build.xcodegen {
+            super::new::generate_xcodegen_file(
+                &member_path,
+                &project.project.name,
+                &project.project.bundle_id,
+                &addon_names,
+                &xcode_env,
+                &signing,
+                &extra_frameworks,
+                arch_mode,
+                project_type,
+            )?;
+        }
+
+        let after_cmake = fs::read_to_string(member_path.join("CMakeLists.txt")).ok();
+        let after_yml = fs::read_to_string(member_path.join("project.yml")).ok();
+        let drifted = before_cmake != after_cmake || before_yml != after_yml;
+
+        if verbose || drifted {
+            println!(
+                "  {} {}",
+                if drifted { "regenerated" } else { "up to date" },
+                member
+            );
+        }
+    }
+
+    println!("✅ Synced {} workspace member(s)", ws.members.len());
+    Ok(())
+}
+
+fn read_member_config(root: &Path, member: &str) -> Option<ProjectConfig> {
+    let manifest = root.join(member).join("oflike.toml");
+    let content = fs::read_to_string(manifest).ok()?;
+    toml::from_str(&content).ok()
+}
+
+fn member_addon_names(project: &ProjectConfig) -> Vec<String> {
+    project
+        .addons
+        .core
+        .iter()
+        .cloned()
+        .chain(project.addons.custom.iter().map(|c| c.name.clone()))
+        .collect()
+}
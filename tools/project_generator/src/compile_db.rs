@@ -0,0 +1,174 @@
+//! Generates `compile_flags.txt` (clangd's simple per-flag format) and, when
+//! the CMake backend is in use, a full `compile_commands.json` compilation
+//! database -- so editor autocomplete/go-to-definition tracks the addon set
+//! without the user running a build first. Regenerated by `new` and by
+//! `add-addon`/`remove-addon` so it never drifts from the project's addons.
+
+use crate::error::{GeneratorError, Result};
+use crate::xcode::XcodeEnvironment;
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub fn generate(
+    project_path: &Path,
+    addon_list: &[String],
+    xcode_env: &XcodeEnvironment,
+    cpp_standard: &str,
+    cmake_present: bool,
+) -> Result<()> {
+    let include_dirs = include_dirs(project_path, addon_list);
+    generate_compile_flags(project_path, &include_dirs, xcode_env, cpp_standard)?;
+    if cmake_present {
+        generate_compile_commands(project_path, &include_dirs, xcode_env, cpp_standard)?;
+    }
+    Ok(())
+}
+
+fn include_dirs(project_path: &Path, addon_list: &[String]) -> Vec<String> {
+    let mut dirs = vec!["src".to_string()];
+    for addon in addon_list {
+        // Vendored addons (`add-addon --mode vendor`) live under `vendor/`.
+        let base = if project_path.join("vendor").join(addon).is_dir() {
+            "vendor"
+        } else {
+            "addons"
+        };
+        dirs.push(format!("{}/{}/src", base, addon));
+        dirs.push(format!("{}/{}/include", base, addon));
+    }
+    dirs
+}
+
+/// Regenerates the index from whatever's on disk: every addon directory
+/// under `addons/`/`vendor/`, the project's `oflike.toml` C++ standard (if
+/// any), and whether a `CMakeLists.txt` exists. Used by `add-addon` and
+/// `remove-addon`, which don't already have the config/xcode-env context
+/// `new::execute` gathers up front.
+pub fn refresh(project_path: &Path) -> Result<()> {
+    let addon_list = discover_addon_names(project_path);
+    let cpp_standard = read_cpp_standard(project_path);
+    let xcode_env = crate::xcode::probe()?;
+    let cmake_present = project_path.join("CMakeLists.txt").exists();
+    generate(project_path, &addon_list, &xcode_env, &cpp_standard, cmake_present)
+}
+
+fn discover_addon_names(project_path: &Path) -> Vec<String> {
+    let mut names = Vec::new();
+    for subdir in ["addons", "vendor"] {
+        let Ok(entries) = fs::read_dir(project_path.join(subdir)) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            if entry.path().is_dir() {
+                if let Some(name) = entry.file_name().to_str() {
+                    names.push(name.to_string());
+                }
+            }
+        }
+    }
+    names.sort();
+    names
+}
+
+fn read_cpp_standard(project_path: &Path) -> String {
+    fs::read_to_string(project_path.join("oflike.toml"))
+        .ok()
+        .and_then(|content| toml::from_str::<crate::config::ProjectConfig>(&content).ok())
+        .map(|config| config.build.cpp_standard)
+        .unwrap_or_else(|| "c++20".to_string())
+}
+
+fn generate_compile_flags(
+    project_path: &Path,
+    include_dirs: &[String],
+    xcode_env: &XcodeEnvironment,
+    cpp_standard: &str,
+) -> Result<()> {
+    let mut lines = vec![
+        format!("-std={}", cpp_standard),
+        "-xobjective-c++".to_string(),
+        format!("-isysroot{}", xcode_env.sdk_path),
+    ];
+    for dir in include_dirs {
+        lines.push(format!("-I{}", dir));
+    }
+
+    fs::write(project_path.join("compile_flags.txt"), lines.join("\n") + "\n")?;
+    Ok(())
+}
+
+#[derive(Serialize)]
+struct CompileCommand {
+    directory: String,
+    file: String,
+    arguments: Vec<String>,
+}
+
+fn generate_compile_commands(
+    project_path: &Path,
+    include_dirs: &[String],
+    xcode_env: &XcodeEnvironment,
+    cpp_standard: &str,
+) -> Result<()> {
+    let mut base_arguments = vec![
+        "clang++".to_string(),
+        format!("-std={}", cpp_standard),
+        format!("-isysroot{}", xcode_env.sdk_path),
+    ];
+    for dir in include_dirs {
+        base_arguments.push(format!("-I{}", dir));
+    }
+
+    let directory = project_path.display().to_string();
+    let commands: Vec<CompileCommand> = find_sources(project_path)
+        .into_iter()
+        .map(|source| {
+            let mut arguments = base_arguments.clone();
+            arguments.push(source.display().to_string());
+            CompileCommand {
+                directory: directory.clone(),
+                file: source.display().to_string(),
+                arguments,
+            }
+        })
+        .collect();
+
+    let json = serde_json::to_string_pretty(&commands).map_err(|e| {
+        GeneratorError::Other(format!("Failed to render compile_commands.json: {}", e))
+    })?;
+    fs::write(project_path.join("compile_commands.json"), json)?;
+    Ok(())
+}
+
+/// Recursively collects `.cpp`/`.mm`/`.cc` files under `src/` and `addons/`.
+fn find_sources(project_path: &Path) -> Vec<PathBuf> {
+    let mut sources = Vec::new();
+    collect_sources(&project_path.join("src"), &mut sources);
+
+    if let Ok(entries) = fs::read_dir(project_path.join("addons")) {
+        for entry in entries.flatten() {
+            collect_sources(&entry.path(), &mut sources);
+        }
+    }
+
+    sources
+}
+
+fn collect_sources(dir: &Path, out: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_sources(&path, out);
+        } else if matches!(
+            path.extension().and_then(|e| e.to_str()),
+            Some("cpp") | Some("mm") | Some("cc")
+        ) {
+            out.push(path);
+        }
+    }
+}
@@ -10,6 +10,49 @@ pub struct Config {
     pub paths: Paths,
     #[serde(default)]
     pub templates: Templates,
+    /// Named addon sources, searched in the order listed here before falling
+    /// back to `paths.registry_url`. Built-in Core/Native names are matched
+    /// before any registry, and only `namespace/id[@version]` specs are
+    /// looked up in a registry. Lets a
+    /// team host a private addon collection without passing `--source` every
+    /// time (see `registry.rs`).
+    #[serde(default)]
+    pub registry: Vec<RegistryConfig>,
+    /// Named bundles of `new` defaults (`oflike-gen new --profile <name>`),
+    /// keyed by profile name.
+    #[serde(default)]
+    pub profiles: HashMap<String, ProfileConfig>,
+}
+
+/// A `[profiles.<name>]` table: a curated template/addon-set/bundle-ID-prefix
+/// bundle that `new` layers over `[defaults]`, below CLI flags.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct ProfileConfig {
+    /// One-line purpose shown by `new --profile list`.
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub template: Option<String>,
+    #[serde(default)]
+    pub addons: Option<Vec<String>>,
+    #[serde(default)]
+    pub bundle_id_prefix: Option<String>,
+}
+
+/// One entry of a `[[registry]]` array: a named addon source. `url` is an
+/// http(s) base URL whose index is fetched over the network, or a local
+/// directory (e.g. an existing git checkout) whose index is read off disk;
+/// git URLs are not cloned. Per-version addon files are always requested over
+/// HTTP at `<url>/<namespace>/<id>/<version>.json` (see `registry.rs`), so the
+/// addons of a local-directory registry can be listed but not resolved.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RegistryConfig {
+    pub name: String,
+    pub url: String,
+    /// Index manifest file name within `url` listing this registry's
+    /// available addons; defaults to `"index.json"`.
+    #[serde(default)]
+    pub index: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -28,6 +71,9 @@ pub struct Defaults {
 pub struct Paths {
     pub oflike_metal_root: Option<String>,
     pub addons_dir: Option<String>,
+    /// Base URL of an addon registry index (see `registry.rs`), used to resolve
+    /// non-builtin addons passed to `add` by `namespace/id[@version]`.
+    pub registry_url: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -46,6 +92,103 @@ pub struct ProjectConfig {
     pub build: BuildConfig,
     #[serde(default)]
     pub paths: ProjectPaths,
+    #[serde(default)]
+    pub bindings: BindingsConfig,
+    #[serde(default)]
+    pub generated: GeneratedFiles,
+}
+
+/// SHA-256 hashes (after normalizing out this project's own substitutions --
+/// see `template_hashes.rs`) of every build file the generator has written
+/// for this project, keyed by filename. `validate --fix` rehashes each file
+/// on disk and compares against these to tell "stale template, safe to
+/// regenerate" from "hand-edited, leave alone".
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct GeneratedFiles {
+    #[serde(default)]
+    pub hashes: HashMap<String, String>,
+    /// SDK path/version substituted into the generated build files at
+    /// generation time, recorded so `validate --fix` can reproduce the exact
+    /// same normalization later even if the local SDK has since changed.
+    #[serde(default)]
+    pub sdk_path: String,
+    #[serde(default)]
+    pub sdk_version: String,
+    /// Signing identity substituted at generation time; see `sdk_path`.
+    #[serde(default)]
+    pub signing_identity: String,
+}
+
+/// Controls generation of Swift bridging headers for addon C++ interfaces
+/// (see `bindings.rs`), used when `entry.mode == "swiftui"`.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct BindingsConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    /// C++ namespaces to export. The header scanner does not track
+    /// namespaces, so this list filters nothing; classes are selected by
+    /// `classes` and `exclude` only.
+    #[serde(default)]
+    pub namespaces: Vec<String>,
+    /// C++ classes to export; empty means "export every public class found".
+    #[serde(default)]
+    pub classes: Vec<String>,
+    /// Prefix applied to generated `extern "C"` trampoline symbol names.
+    #[serde(default = "default_bindings_prefix")]
+    pub prefix: String,
+    /// Types to keep opaque (passed as pointers) rather than map to a Swift
+    /// primitive.
+    #[serde(default)]
+    pub opaque_types: Vec<String>,
+    /// Classes or methods to skip even if otherwise exported.
+    #[serde(default)]
+    pub exclude: Vec<String>,
+}
+
+fn default_bindings_prefix() -> String {
+    "ofl_".to_string()
+}
+
+pub const WORKSPACE_FILE_NAME: &str = "oflike-workspace.toml";
+
+/// Root manifest for a multi-project workspace: member project paths plus
+/// build/addon settings `workspace sync` re-applies across all of them.
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct WorkspaceConfig {
+    #[serde(default)]
+    pub members: Vec<String>,
+    #[serde(default)]
+    pub addons_dir: Option<String>,
+    #[serde(default)]
+    pub oflike_metal_root: Option<String>,
+    #[serde(default)]
+    pub shared_build: BuildConfigOverride,
+    #[serde(default)]
+    pub shared_addons: AddonsConfig,
+}
+
+impl WorkspaceConfig {
+    pub fn load(root: &std::path::Path) -> crate::error::Result<Self> {
+        let path = root.join(WORKSPACE_FILE_NAME);
+        if !path.exists() {
+            return Err(crate::error::GeneratorError::Other(format!(
+                "No {} found in {}. Run `oflike-gen workspace init` first.",
+                WORKSPACE_FILE_NAME,
+                root.display()
+            )));
+        }
+        let content = std::fs::read_to_string(&path)?;
+        toml::from_str(&content)
+            .map_err(|e| crate::error::GeneratorError::Config(format!("{}: {}", path.display(), e)))
+    }
+
+    pub fn save(&self, root: &std::path::Path) -> crate::error::Result<()> {
+        let content = toml::to_string_pretty(self).map_err(|e| {
+            crate::error::GeneratorError::Config(format!("{}: {}", WORKSPACE_FILE_NAME, e))
+        })?;
+        std::fs::write(root.join(WORKSPACE_FILE_NAME), content)?;
+        Ok(())
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -77,6 +220,10 @@ pub struct CustomAddon {
     pub name: String,
     pub mode: String,
     pub source: String,
+    /// Semver requirement (e.g. `"^1.2"`, `">=0.9, <2.0"`) constraining which
+    /// registry-resolved version satisfies this entry. Unset means any.
+    #[serde(default)]
+    pub version_req: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -93,6 +240,46 @@ pub struct BuildConfig {
     pub cpp_standard: String,
 }
 
+/// A layer of `BuildConfig` overrides -- e.g. `[shared_build]` in a workspace
+/// manifest -- where every field is `Option` so "this layer didn't mention
+/// the setting" is distinguishable from "this layer set it to the same
+/// value as the baked-in default." `BuildConfig` itself can't make that
+/// distinction (its fields are always filled in by serde's `default =`),
+/// which is exactly why applying one as an unconditional copy, or folding it
+/// in by ANDing booleans, both go wrong: the former clobbers a member's own
+/// settings with nothing-but-defaults, the latter can turn a setting off but
+/// never back on.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct BuildConfigOverride {
+    pub cmake: Option<bool>,
+    pub xcodegen: Option<bool>,
+    pub min_macos: Option<String>,
+    pub swift_version: Option<String>,
+    pub cpp_standard: Option<String>,
+}
+
+impl BuildConfig {
+    /// Applies every field `other` actually set, leaving fields it left
+    /// unset at whatever `self` already had.
+    pub fn apply_override(&mut self, other: &BuildConfigOverride) {
+        if let Some(cmake) = other.cmake {
+            self.cmake = cmake;
+        }
+        if let Some(xcodegen) = other.xcodegen {
+            self.xcodegen = xcodegen;
+        }
+        if let Some(min_macos) = &other.min_macos {
+            self.min_macos = min_macos.clone();
+        }
+        if let Some(swift_version) = &other.swift_version {
+            self.swift_version = swift_version.clone();
+        }
+        if let Some(cpp_standard) = &other.cpp_standard {
+            self.cpp_standard = cpp_standard.clone();
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ProjectPaths {
     #[serde(default = "default_src")]
@@ -121,6 +308,7 @@ impl Default for Paths {
         Self {
             oflike_metal_root: None,
             addons_dir: None,
+            registry_url: None,
         }
     }
 }
@@ -219,6 +407,173 @@ pub fn load_global_config() -> Option<Config> {
     toml::from_str(&content).ok()
 }
 
+/// Combines two layers of the same config section, where `other` (the
+/// higher-precedence layer) overwrites a field only if it differs from that
+/// field's baked-in default -- i.e. the layer actually set it.
+pub trait Merge {
+    fn merge(&mut self, other: Self);
+}
+
+impl Merge for Defaults {
+    fn merge(&mut self, other: Self) {
+        if other.entry_mode != default_entry_mode() {
+            self.entry_mode = other.entry_mode;
+        }
+        if other.addon_mode != default_addon_mode() {
+            self.addon_mode = other.addon_mode;
+        }
+        if !other.author.is_empty() {
+            self.author = other.author;
+        }
+        if other.bundle_id_prefix != default_bundle_id_prefix() {
+            self.bundle_id_prefix = other.bundle_id_prefix;
+        }
+    }
+}
+
+impl Merge for Paths {
+    fn merge(&mut self, other: Self) {
+        if other.oflike_metal_root.is_some() {
+            self.oflike_metal_root = other.oflike_metal_root;
+        }
+        if other.addons_dir.is_some() {
+            self.addons_dir = other.addons_dir;
+        }
+        if other.registry_url.is_some() {
+            self.registry_url = other.registry_url;
+        }
+    }
+}
+
+impl Merge for Templates {
+    fn merge(&mut self, other: Self) {
+        if other.default_template != default_template() {
+            self.default_template = other.default_template;
+        }
+    }
+}
+
+impl Merge for ProjectPaths {
+    fn merge(&mut self, other: Self) {
+        if other.src != default_src() {
+            self.src = other.src;
+        }
+        if other.data != default_data() {
+            self.data = other.data;
+        }
+        if other.resources != default_resources() {
+            self.resources = other.resources;
+        }
+        if other.addons != default_addons() {
+            self.addons = other.addons;
+        }
+    }
+}
+
+impl Merge for Config {
+    fn merge(&mut self, other: Self) {
+        self.defaults.merge(other.defaults);
+        self.paths.merge(other.paths);
+        self.templates.merge(other.templates);
+        for reg in other.registry {
+            // A later layer's entry with the same name overrides the
+            // earlier layer's in place, keeping its priority position;
+            // unseen names are appended with lower priority.
+            match self.registry.iter_mut().find(|r| r.name == reg.name) {
+                Some(existing) => *existing = reg,
+                None => self.registry.push(reg),
+            }
+        }
+        for (name, profile) in other.profiles {
+            self.profiles.insert(name, profile);
+        }
+    }
+}
+
+/// CLI-flag overrides, applied last in the resolution chain. Every field is
+/// `Option` so "not passed on the command line" is distinguishable from "set
+/// to the default value".
+#[derive(Debug, Default)]
+pub struct ConfigOverride {
+    pub entry_mode: Option<String>,
+    pub addon_mode: Option<String>,
+    pub bundle_id_prefix: Option<String>,
+}
+
+impl ConfigOverride {
+    pub fn apply(&self, config: &mut Config) {
+        if let Some(entry_mode) = &self.entry_mode {
+            config.defaults.entry_mode = entry_mode.clone();
+        }
+        if let Some(addon_mode) = &self.addon_mode {
+            config.defaults.addon_mode = addon_mode.clone();
+        }
+        if let Some(prefix) = &self.bundle_id_prefix {
+            config.defaults.bundle_id_prefix = prefix.clone();
+        }
+    }
+}
+
+/// A loaded config layer tagged with the file it came from, so a bad value
+/// can be reported with `GeneratorError::Config` pointing at its source.
+#[derive(Debug)]
+pub struct WithPath<T> {
+    pub inner: T,
+    pub path: PathBuf,
+}
+
+fn load_layer(path: PathBuf) -> Option<crate::error::Result<WithPath<Config>>> {
+    if !path.exists() {
+        return None;
+    }
+    Some(
+        std::fs::read_to_string(&path)
+            .map_err(crate::error::GeneratorError::Io)
+            .and_then(|content| {
+                toml::from_str(&content).map_err(|e| {
+                    crate::error::GeneratorError::Config(format!(
+                        "{}: {}",
+                        path.display(),
+                        e
+                    ))
+                })
+            })
+            .map(|inner| WithPath { inner, path }),
+    )
+}
+
+/// Resolve effective configuration by merging, in precedence order: built-in
+/// defaults -> global `~/.oflike-gen.toml` -> per-project `./.oflike-gen.toml`
+/// (the file `init --local` writes; not to be confused with the project
+/// *manifest* `oflike.toml`, which is a `ProjectConfig`, not a `Config`) ->
+/// `overrides` (parsed from CLI flags). Each file layer overwrites only the
+/// fields it sets to a non-default value (see [`Merge`]); `overrides` overwrite
+/// every field that was passed.
+pub fn resolve_config(
+    project_dir: &std::path::Path,
+    overrides: &ConfigOverride,
+) -> crate::error::Result<Config> {
+    let mut resolved = Config {
+        defaults: Defaults::default(),
+        paths: Paths::default(),
+        templates: Templates::default(),
+        registry: Vec::new(),
+        profiles: HashMap::new(),
+    };
+
+    let home = std::env::var("HOME").ok().map(PathBuf::from);
+    if let Some(layer) = home.and_then(|h| load_layer(h.join(".oflike-gen.toml"))) {
+        resolved.merge(layer?.inner);
+    }
+
+    if let Some(layer) = load_layer(project_dir.join(".oflike-gen.toml")) {
+        resolved.merge(layer?.inner);
+    }
+
+    overrides.apply(&mut resolved);
+    Ok(resolved)
+}
+
 pub fn get_author_from_git() -> Option<String> {
     std::process::Command::new("git")
         .args(&["config", "user.name"])
@@ -0,0 +1,180 @@
+//! Transitive addon dependency resolution: given root `namespace/id` plus a
+//! semver requirement, follows each selected version's declared dependencies
+//! (`AddonFile::dependencies`), accumulating constraints per addon and
+//! selecting the highest version that satisfies all of them. Fails with a
+//! `GeneratorError` naming the conflicting requesters on an unsatisfiable
+//! constraint set, or a cycle.
+
+use crate::error::{GeneratorError, Result};
+use crate::registry::{self, AddonDescriptor, RegistryIndex};
+use semver::{Version, VersionReq};
+use std::collections::HashMap;
+
+/// A single resolved addon and the version selected for it.
+#[derive(Debug, Clone)]
+pub struct ResolvedDependency {
+    pub namespace: String,
+    pub id: String,
+    pub version: String,
+}
+
+#[derive(Debug, Clone)]
+struct Requirement {
+    req: VersionReq,
+    requested_by: String,
+}
+
+/// Resolve `roots` (each a `namespace/id` key plus a semver requirement
+/// string, `"*"` for "any") against `index`, pulling in transitive
+/// dependencies declared by each selected version.
+pub fn resolve(
+    index: &RegistryIndex,
+    index_url: &str,
+    roots: &[(String, String)],
+) -> Result<Vec<ResolvedDependency>> {
+    let mut constraints: HashMap<String, Vec<Requirement>> = HashMap::new();
+    for (key, req_str) in roots {
+        add_constraint(&mut constraints, key, req_str, "<root>")?;
+    }
+
+    let mut resolved: HashMap<String, String> = HashMap::new();
+    let mut path: Vec<String> = Vec::new();
+    for (key, _) in roots {
+        resolve_one(key, index, index_url, &mut constraints, &mut resolved, &mut path)?;
+    }
+
+    let mut out: Vec<ResolvedDependency> = resolved
+        .into_iter()
+        .map(|(key, version)| {
+            let (namespace, id) = key.split_once('/').expect("validated above");
+            ResolvedDependency {
+                namespace: namespace.to_string(),
+                id: id.to_string(),
+                version,
+            }
+        })
+        .collect();
+    out.sort_by(|a, b| (&a.namespace, &a.id).cmp(&(&b.namespace, &b.id)));
+    Ok(out)
+}
+
+/// Resolves a single `namespace/id` key, recursing into its declared
+/// dependencies depth-first. `path` holds the chain of keys currently being
+/// resolved (an ancestor stack, not a visited-ever set), so a key reappearing
+/// while it's still on `path` is a genuine cycle; a key already in `resolved`
+/// is instead re-validated against whatever constraints have accumulated
+/// since it was selected, so a conflicting requirement discovered after the
+/// fact is still caught instead of silently ignored.
+fn resolve_one(
+    key: &str,
+    index: &RegistryIndex,
+    index_url: &str,
+    constraints: &mut HashMap<String, Vec<Requirement>>,
+    resolved: &mut HashMap<String, String>,
+    path: &mut Vec<String>,
+) -> Result<()> {
+    if path.iter().any(|k| k == key) {
+        path.push(key.to_string());
+        return Err(GeneratorError::Other(format!(
+            "Cycle detected while resolving addon dependencies: {}",
+            path.join(" -> ")
+        )));
+    }
+
+    if let Some(existing) = resolved.get(key) {
+        let reqs = constraints.get(key).cloned().unwrap_or_default();
+        let version = Version::parse(existing).map_err(|e| {
+            GeneratorError::Other(format!("Invalid resolved version '{}' for '{}': {}", existing, key, e))
+        })?;
+        if !reqs.iter().all(|r| r.req.matches(&version)) {
+            let requesters: Vec<String> = reqs
+                .iter()
+                .map(|r| format!("{} requires {}", r.requested_by, r.req))
+                .collect();
+            return Err(GeneratorError::Other(format!(
+                "'{}' was already resolved to {} but a later requirement conflicts: {}",
+                key,
+                existing,
+                requesters.join(", ")
+            )));
+        }
+        return Ok(());
+    }
+
+    path.push(key.to_string());
+
+    let (namespace, id) = key.split_once('/').ok_or_else(|| {
+        GeneratorError::Other(format!("Invalid addon key '{}': expected namespace/id", key))
+    })?;
+    let entry = index
+        .find(namespace, id)
+        .ok_or_else(|| GeneratorError::AddonNotFound(key.to_string()))?;
+
+    let reqs = constraints.get(key).cloned().unwrap_or_default();
+    let version = select_version(&entry.versions, &reqs, key)?;
+
+    let descriptor = AddonDescriptor {
+        namespace: namespace.to_string(),
+        id: id.to_string(),
+        version: version.clone(),
+    };
+    let file = registry::fetch_addon_file(index_url, &descriptor)?;
+
+    // Record every constraint this addon's dependencies impose before
+    // recursing into any of them, so whichever one we visit first already
+    // sees its siblings' requirements.
+    for (dep_key, dep_req) in &file.dependencies {
+        add_constraint(constraints, dep_key, dep_req, key)?;
+    }
+    for (dep_key, _) in &file.dependencies {
+        resolve_one(dep_key, index, index_url, constraints, resolved, path)?;
+    }
+
+    resolved.insert(key.to_string(), version);
+    path.pop();
+    Ok(())
+}
+
+fn add_constraint(
+    constraints: &mut HashMap<String, Vec<Requirement>>,
+    key: &str,
+    req_str: &str,
+    requested_by: &str,
+) -> Result<()> {
+    let req = VersionReq::parse(req_str).map_err(|e| {
+        GeneratorError::Other(format!(
+            "Invalid version requirement '{}' for '{}': {}",
+            req_str, key, e
+        ))
+    })?;
+    constraints
+        .entry(key.to_string())
+        .or_default()
+        .push(Requirement {
+            req,
+            requested_by: requested_by.to_string(),
+        });
+    Ok(())
+}
+
+fn select_version(versions: &[String], reqs: &[Requirement], key: &str) -> Result<String> {
+    let mut candidates: Vec<Version> = versions.iter().filter_map(|v| Version::parse(v).ok()).collect();
+    candidates.sort();
+    candidates.reverse();
+
+    for candidate in &candidates {
+        if reqs.iter().all(|r| r.req.matches(candidate)) {
+            return Ok(candidate.to_string());
+        }
+    }
+
+    let requesters: Vec<String> = reqs
+        .iter()
+        .map(|r| format!("{} requires {}", r.requested_by, r.req))
+        .collect();
+    Err(GeneratorError::Other(format!(
+        "No version of '{}' satisfies all requirements: {}",
+        key,
+        requesters.join(", ")
+    )))
+}
@@ -16,12 +16,21 @@ pub enum GeneratorError {
     #[error("Addon not found: {0}")]
     AddonNotFound(String),
 
+    #[error("Addon hash mismatch: {0}")]
+    HashMismatch(String),
+
     #[error("Invalid addon mode: {0}")]
     InvalidAddonMode(String),
 
+    #[error("Invalid entry mode: {0}")]
+    InvalidEntryMode(String),
+
     #[error("Invalid template: {0}")]
     InvalidTemplate(String),
 
+    #[error("Invalid generator: {0}")]
+    InvalidGenerator(String),
+
     #[error("Project already exists: {0}")]
     ProjectExists(String),
 
@@ -0,0 +1,234 @@
+//! Pluggable project-generator backends. `new::execute` used to hardcode
+//! XcodeGen + CMake output; this lets `--generator` pick among XcodeGen
+//! (default), a pure CMake project (no XcodeGen involved at all), or a
+//! SwiftPM package, so users without XcodeGen installed can still scaffold
+//! a buildable project.
+
+use crate::addon_manifest::AddonDependencies;
+use crate::arch::ArchMode;
+use crate::error::Result;
+use crate::project_type::ProjectType;
+use crate::signing::ResolvedSigning;
+use crate::xcode::XcodeEnvironment;
+use std::fs;
+use std::path::PathBuf;
+
+/// Shared inputs every backend needs, gathered once in `new::execute`
+/// instead of threading a dozen individual parameters through each backend.
+pub struct ProjectContext {
+    pub project_path: PathBuf,
+    pub project_name: String,
+    pub bundle_id: String,
+    pub author: String,
+    pub addon_list: Vec<String>,
+    pub xcode_env: XcodeEnvironment,
+    pub signing: ResolvedSigning,
+    pub addon_dependencies: AddonDependencies,
+    pub arch: ArchMode,
+    pub project_type: ProjectType,
+}
+
+pub trait ProjectGenerator {
+    /// Writes this backend's build manifest(s) into `ctx.project_path`.
+    fn generate_build_files(&self, ctx: &ProjectContext) -> Result<()>;
+
+    /// Any post-write step the backend needs (e.g. `xcodegen generate` to
+    /// materialize the `.xcodeproj`). Most backends need nothing.
+    fn run_after_generate(&self, _ctx: &ProjectContext, _verbose: bool) -> Result<()> {
+        Ok(())
+    }
+
+    /// One-line description of what was generated, for `new`'s summary.
+    fn summary(&self) -> &'static str;
+}
+
+pub fn resolve(name: &str) -> Box<dyn ProjectGenerator> {
+    match name {
+        "cmake" => Box::new(CMakeGenerator),
+        "swiftpm" => Box::new(SwiftPmGenerator),
+        _ => Box::new(XcodeGenGenerator),
+    }
+}
+
+/// Default backend: both XcodeGen's `project.yml` and a CMakeLists.txt
+/// (CMake can still drive an Xcode-generator build), then runs
+/// `xcodegen generate` to produce the `.xcodeproj`.
+pub struct XcodeGenGenerator;
+
+impl ProjectGenerator for XcodeGenGenerator {
+    fn generate_build_files(&self, ctx: &ProjectContext) -> Result<()> {
+        crate::commands::new::generate_cmake_file(
+            &ctx.project_path,
+            &ctx.project_name,
+            &ctx.bundle_id,
+            &ctx.addon_list,
+            &ctx.xcode_env,
+            &ctx.signing,
+            &ctx.addon_dependencies.frameworks,
+            ctx.arch,
+            ctx.project_type,
+        )?;
+        crate::commands::new::generate_xcodegen_file(
+            &ctx.project_path,
+            &ctx.project_name,
+            &ctx.bundle_id,
+            &ctx.addon_list,
+            &ctx.xcode_env,
+            &ctx.signing,
+            &ctx.addon_dependencies.frameworks,
+            ctx.arch,
+            ctx.project_type,
+        )
+    }
+
+    fn run_after_generate(&self, ctx: &ProjectContext, verbose: bool) -> Result<()> {
+        crate::commands::new::run_xcodegen(&ctx.project_path, verbose)
+    }
+
+    fn summary(&self) -> &'static str {
+        "XcodeGen project.yml + CMakeLists.txt"
+    }
+}
+
+/// Pure CMake backend: skips XcodeGen/`run_xcodegen` entirely, leaving a
+/// project buildable with `cmake .. -G Xcode` alone.
+pub struct CMakeGenerator;
+
+impl ProjectGenerator for CMakeGenerator {
+    fn generate_build_files(&self, ctx: &ProjectContext) -> Result<()> {
+        crate::commands::new::generate_cmake_file(
+            &ctx.project_path,
+            &ctx.project_name,
+            &ctx.bundle_id,
+            &ctx.addon_list,
+            &ctx.xcode_env,
+            &ctx.signing,
+            &ctx.addon_dependencies.frameworks,
+            ctx.arch,
+            ctx.project_type,
+        )
+    }
+
+    fn summary(&self) -> &'static str {
+        "CMakeLists.txt"
+    }
+}
+
+/// SwiftPM backend: emits a `Package.swift` with a mixed C++/Swift
+/// executable target and an oflike-metal package dependency, for users who
+/// live in SwiftPM/CLI workflows instead of XcodeGen.
+pub struct SwiftPmGenerator;
+
+impl ProjectGenerator for SwiftPmGenerator {
+    fn generate_build_files(&self, ctx: &ProjectContext) -> Result<()> {
+        // `Package.swift` has no equivalent of an Xcode target type, a
+        // signing identity, or an explicit ARCHS list -- warn instead of
+        // silently emitting a manifest that drops the request.
+        if ctx.project_type != ProjectType::App {
+            eprintln!(
+                "Warning: the swiftpm backend only emits an executable target; {:?} is ignored",
+                ctx.project_type
+            );
+        }
+        if !ctx.signing.identity.is_empty() {
+            eprintln!("Warning: the swiftpm backend doesn't sign its build products; --signing is ignored");
+        }
+        if ctx.arch.archs_value().is_some() {
+            eprintln!("Warning: the swiftpm backend always builds for the host architecture; --arch is ignored");
+        }
+
+        let addon_targets = ctx
+            .addon_list
+            .iter()
+            .map(|addon| format!("\"addons/{}\"", addon))
+            .collect::<Vec<_>>()
+            .join(",\n                    ");
+        let exclude_line = if addon_targets.is_empty() {
+            String::new()
+        } else {
+            format!(
+                "\n                exclude: [\n                    {}\n                ],",
+                addon_targets
+            )
+        };
+
+        let package_dependencies = ctx
+            .addon_dependencies
+            .swift_packages
+            .iter()
+            .map(|pkg| match &pkg.from {
+                Some(from) => format!("        .package(url: \"{}\", from: \"{}\")", pkg.url, from),
+                None => format!("        .package(url: \"{}\", branch: \"main\")", pkg.url),
+            })
+            .collect::<Vec<_>>()
+            .join(",\n");
+        let package_dependencies_line = if package_dependencies.is_empty() {
+            String::new()
+        } else {
+            format!(",\n{}", package_dependencies)
+        };
+
+        let product_dependencies = ctx
+            .addon_dependencies
+            .swift_packages
+            .iter()
+            .map(|pkg| {
+                format!(
+                    "                .product(name: \"{}\", package: \"{}\")",
+                    pkg.name, pkg.name
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",\n");
+        let product_dependencies_line = if product_dependencies.is_empty() {
+            String::new()
+        } else {
+            format!(",\n{}", product_dependencies)
+        };
+
+        let package_content = format!(
+            r#"// swift-tools-version:5.9
+import PackageDescription
+
+let package = Package(
+    name: "{project_name}",
+    platforms: [
+        .macOS("{deployment_target}")
+    ],
+    dependencies: [
+        .package(path: "../../"){package_dependencies_line}
+    ],
+    targets: [
+        .executableTarget(
+            name: "{project_name}",
+            dependencies: [
+                .product(name: "oflike-metal", package: "oflike-metal"){product_dependencies_line}
+            ],
+            path: "src",{exclude_line}
+            cxxSettings: [
+                .unsafeFlags(["-std=c++20"])
+            ],
+            swiftSettings: [
+                .interoperabilityMode(.Cxx)
+            ]
+        )
+    ],
+    cLanguageStandard: .c17,
+    cxxLanguageStandard: .cxx20
+)
+"#,
+            project_name = ctx.project_name,
+            deployment_target = crate::xcode::clamp_deployment_target("13.0", &ctx.xcode_env),
+            exclude_line = exclude_line,
+            package_dependencies_line = package_dependencies_line,
+            product_dependencies_line = product_dependencies_line,
+        );
+
+        fs::write(ctx.project_path.join("Package.swift"), package_content)?;
+        Ok(())
+    }
+
+    fn summary(&self) -> &'static str {
+        "SwiftPM Package.swift"
+    }
+}
@@ -0,0 +1,130 @@
+//! `oflike.lock`: pins the exact resolved addon set for a project so that
+//! `install`/`restore` can recreate it byte-for-byte without touching the
+//! registry index again.
+
+use crate::error::{GeneratorError, Result};
+use crate::registry::ResolvedAddon;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+pub const LOCK_FILE_NAME: &str = "oflike.lock";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LockedAddon {
+    pub namespace: String,
+    pub id: String,
+    pub version: String,
+    /// The concrete mirror URL that was actually used to fetch this addon.
+    pub resolved_url: String,
+    /// sha256 of the downloaded bytes, verified again on `install`/`restore`.
+    pub sha256: String,
+}
+
+impl From<&ResolvedAddon> for LockedAddon {
+    fn from(resolved: &ResolvedAddon) -> Self {
+        Self {
+            namespace: resolved.descriptor.namespace.clone(),
+            id: resolved.descriptor.id.clone(),
+            version: resolved.descriptor.version.clone(),
+            resolved_url: resolved.url.clone(),
+            sha256: resolved.sha256.clone(),
+        }
+    }
+}
+
+/// An addon vendored as a git submodule under `vendor/<name>/` (`add-addon
+/// --mode vendor`), as opposed to one resolved from a registry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VendoredAddon {
+    pub name: String,
+    pub url: String,
+    /// Commit SHA checked out when this entry was locked, re-checked-out by
+    /// `install`.
+    pub sha: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct LockFile {
+    pub version: u32,
+    #[serde(default)]
+    pub addons: Vec<LockedAddon>,
+    #[serde(default)]
+    pub vendored: Vec<VendoredAddon>,
+}
+
+impl LockFile {
+    pub fn load(project_dir: &Path) -> Result<Option<Self>> {
+        let path = project_dir.join(LOCK_FILE_NAME);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = std::fs::read_to_string(&path)?;
+        let lock = toml::from_str(&content)
+            .map_err(|e| GeneratorError::Config(format!("{}: {}", path.display(), e)))?;
+        Ok(Some(lock))
+    }
+
+    pub fn load_or_default(project_dir: &Path) -> Result<Self> {
+        Ok(Self::load(project_dir)?.unwrap_or(Self {
+            version: 1,
+            addons: Vec::new(),
+            vendored: Vec::new(),
+        }))
+    }
+
+    pub fn save(&self, project_dir: &Path) -> Result<()> {
+        let content = toml::to_string_pretty(self)
+            .map_err(|e| GeneratorError::Config(format!("{}: {}", LOCK_FILE_NAME, e)))?;
+        std::fs::write(project_dir.join(LOCK_FILE_NAME), content)?;
+        Ok(())
+    }
+
+    pub fn upsert(&mut self, locked: LockedAddon) {
+        self.addons
+            .retain(|a| !(a.namespace == locked.namespace && a.id == locked.id));
+        self.addons.push(locked);
+    }
+
+    pub fn remove(&mut self, namespace: &str, id: &str) {
+        self.addons
+            .retain(|a| !(a.namespace == namespace && a.id == id));
+    }
+
+    pub fn upsert_vendored(&mut self, vendored: VendoredAddon) {
+        self.vendored.retain(|v| v.name != vendored.name);
+        self.vendored.push(vendored);
+    }
+
+    pub fn remove_vendored(&mut self, name: &str) {
+        self.vendored.retain(|v| v.name != name);
+    }
+
+    /// Compares the lock against a project's declared `namespace/id` addon
+    /// list and reports drift in either direction.
+    pub fn staleness(&self, declared: &[String]) -> Vec<String> {
+        let locked: Vec<String> = self
+            .addons
+            .iter()
+            .map(|a| format!("{}/{}", a.namespace, a.id))
+            .collect();
+
+        let mut warnings = Vec::new();
+        for id in declared {
+            if !locked.contains(id) {
+                warnings.push(format!(
+                    "'{}' is declared in oflike.toml but missing from {}",
+                    id, LOCK_FILE_NAME
+                ));
+            }
+        }
+        for id in &locked {
+            if !declared.contains(id) {
+                warnings.push(format!(
+                    "'{}' is locked in {} but no longer declared",
+                    id, LOCK_FILE_NAME
+                ));
+            }
+        }
+        warnings
+    }
+}
@@ -1,10 +1,24 @@
 use clap::{Parser, Subcommand};
 use std::process;
 
+mod addon_manifest;
+mod arch;
+mod bindings;
 mod commands;
+mod compile_db;
 mod config;
+mod deps;
 mod error;
+mod generators;
+mod lockfile;
+mod podfile;
+mod project_type;
+mod registry;
+mod signing;
+mod template_hashes;
 mod utils;
+mod vcs;
+mod xcode;
 
 use error::Result;
 
@@ -22,10 +36,33 @@ struct Cli {
     #[arg(long, global = true, value_name = "PATH")]
     config: Option<String>,
 
+    /// Override the resolved entry mode default ('swiftui' or 'ofmain')
+    #[arg(long, global = true, value_name = "MODE")]
+    entry_mode: Option<String>,
+
+    /// Override the resolved addon mode default
+    #[arg(long, global = true, value_name = "MODE")]
+    override_addon_mode: Option<String>,
+
+    /// Override the resolved bundle ID prefix default
+    #[arg(long, global = true, value_name = "PREFIX")]
+    bundle_id_prefix: Option<String>,
+
     #[command(subcommand)]
     command: Commands,
 }
 
+impl Cli {
+    /// CLI-flag overrides, layered last over defaults/global-config/project-config.
+    fn config_override(&self) -> config::ConfigOverride {
+        config::ConfigOverride {
+            entry_mode: self.entry_mode.clone(),
+            addon_mode: self.override_addon_mode.clone(),
+            bundle_id_prefix: self.bundle_id_prefix.clone(),
+        }
+    }
+}
+
 #[derive(Subcommand)]
 enum Commands {
     /// Generate a new oflike-metal project
@@ -46,8 +83,27 @@ enum Commands {
         path: Option<String>,
 
         /// Project template
-        #[arg(long, value_name = "NAME", default_value = "basic")]
-        template: String,
+        #[arg(long, value_name = "NAME")]
+        template: Option<String>,
+
+        /// Apply a named `[profiles.<name>]` bundle of template/addons/
+        /// bundle-id-prefix defaults (CLI flags still win). Pass `list` to
+        /// print the configured profiles and their purpose instead of
+        /// creating a project.
+        #[arg(long, value_name = "NAME")]
+        profile: Option<String>,
+
+        /// Project-generator backend: `xcodegen`, `swiftpm`, or `cmake`
+        #[arg(long, value_name = "BACKEND", default_value = "xcodegen")]
+        generator: String,
+
+        /// Target architecture(s): `native`, `universal`, `arm64`, or `x86_64`
+        #[arg(long, value_name = "ARCH", default_value = "native")]
+        arch: String,
+
+        /// Project type: `app`, `framework`, or `static-lib`
+        #[arg(long, value_name = "TYPE", default_value = "app")]
+        r#type: String,
 
         /// macOS bundle identifier
         #[arg(long, value_name = "ID")]
@@ -57,13 +113,56 @@ enum Commands {
         #[arg(long, value_name = "NAME")]
         author: Option<String>,
 
-        /// Skip git initialization
+        /// Version-control backend: `git`, `hg`, `fossil`, `pijul`, or `none`
+        #[arg(long, value_name = "MODE", default_value = "git")]
+        vcs: String,
+
+        /// Deprecated: use `--vcs none` instead
         #[arg(long)]
         no_git: bool,
 
         /// Skip README.md generation
         #[arg(long)]
         no_readme: bool,
+
+        /// Generate Swift bridging headers for addon C++ interfaces when the
+        /// entry mode is `swiftui` (see `bindings.rs`)
+        #[arg(long)]
+        bindings: bool,
+
+        /// Code-signing mode: `none`, `adhoc`, `development` (auto-detect),
+        /// or an explicit identity (SHA-1 hash or name)
+        #[arg(long, value_name = "MODE", default_value = "none")]
+        signing: String,
+
+        /// Enable the App Sandbox entitlement
+        #[arg(long)]
+        sandbox: bool,
+
+        /// Enable the hardened runtime and its JIT/camera/microphone
+        /// entitlements where also requested
+        #[arg(long)]
+        hardened_runtime: bool,
+
+        /// Allow unsigned/JIT-compiled memory (requires --hardened-runtime)
+        #[arg(long)]
+        jit: bool,
+
+        /// Request camera access
+        #[arg(long)]
+        camera: bool,
+
+        /// Request microphone access
+        #[arg(long)]
+        microphone: bool,
+
+        /// Request outgoing network connections
+        #[arg(long)]
+        network_client: bool,
+
+        /// Request incoming network connections
+        #[arg(long)]
+        network_server: bool,
     },
 
     /// Add an addon to an existing project
@@ -111,6 +210,11 @@ enum Commands {
         /// List addons in project
         #[arg(long, value_name = "PATH", default_value = ".")]
         project: String,
+
+        /// Aggregate addon usage across every workspace member (`project` is
+        /// then the workspace root)
+        #[arg(long)]
+        workspace: bool,
     },
 
     /// Initialize oflike-gen configuration
@@ -134,6 +238,73 @@ enum Commands {
         #[arg(long)]
         fix: bool,
     },
+
+    /// Re-materialize addons pinned in oflike.lock
+    Install {
+        /// Target project directory
+        #[arg(long, value_name = "PATH", default_value = ".")]
+        project: String,
+    },
+
+    /// Manage a multi-project workspace
+    Workspace {
+        #[command(subcommand)]
+        action: WorkspaceAction,
+    },
+
+    /// Watch a project and incrementally regenerate/rebuild/run it on change
+    Watch {
+        /// Target project directory
+        #[arg(long, value_name = "PATH", default_value = ".")]
+        project: String,
+
+        /// Build configuration
+        #[arg(long, value_name = "NAME", default_value = "Debug")]
+        configuration: String,
+
+        /// Launch (or relaunch) the built app after each successful build
+        #[arg(long)]
+        launch: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum WorkspaceAction {
+    /// Create a new workspace manifest in the given root
+    Init {
+        #[arg(long, value_name = "PATH", default_value = ".")]
+        root: String,
+    },
+
+    /// List workspace members
+    List {
+        #[arg(long, value_name = "PATH", default_value = ".")]
+        root: String,
+    },
+
+    /// Add a project directory to the workspace
+    Add {
+        /// Path to the member project, relative to the workspace root
+        member: String,
+
+        #[arg(long, value_name = "PATH", default_value = ".")]
+        root: String,
+    },
+
+    /// Remove a project directory from the workspace
+    Remove {
+        /// Path to the member project, relative to the workspace root
+        member: String,
+
+        #[arg(long, value_name = "PATH", default_value = ".")]
+        root: String,
+    },
+
+    /// Re-apply shared build settings and addons across every member
+    Sync {
+        #[arg(long, value_name = "PATH", default_value = ".")]
+        root: String,
+    },
 }
 
 fn main() {
@@ -146,6 +317,8 @@ fn main() {
 }
 
 fn run(cli: Cli) -> Result<()> {
+    let overrides = cli.config_override();
+
     match cli.command {
         Commands::New {
             project_name,
@@ -153,22 +326,54 @@ fn run(cli: Cli) -> Result<()> {
             addon_mode,
             path,
             template,
+            profile,
+            generator,
+            arch,
+            r#type,
             bundle_id,
             author,
+            vcs,
             no_git,
             no_readme,
+            bindings,
+            signing,
+            sandbox,
+            hardened_runtime,
+            jit,
+            camera,
+            microphone,
+            network_client,
+            network_server,
         } => {
+            let entitlements = signing::EntitlementsConfig {
+                app_sandbox: sandbox,
+                hardened_runtime,
+                jit,
+                camera,
+                microphone,
+                network_client,
+                network_server,
+            };
             commands::new::execute(
                 &project_name,
                 addons.as_deref(),
                 &addon_mode,
                 path.as_deref(),
-                &template,
+                template.as_deref(),
+                profile.as_deref(),
                 bundle_id.as_deref(),
                 author.as_deref(),
+                &vcs,
                 no_git,
                 no_readme,
+                bindings,
                 cli.verbose,
+                &overrides,
+                &signing,
+                &entitlements,
+                &generator,
+                &arch,
+                &r#type,
             )
         }
 
@@ -193,14 +398,42 @@ fn run(cli: Cli) -> Result<()> {
             keep_files,
         } => commands::remove_addon::execute(&addon_name, &project, keep_files, cli.verbose),
 
-        Commands::ListAddons { available, project } => {
-            commands::list_addons::execute(available, &project, cli.verbose)
-        }
+        Commands::ListAddons {
+            available,
+            project,
+            workspace,
+        } => commands::list_addons::execute_with_workspace(available, &project, workspace, cli.verbose),
 
         Commands::Init { global, local } => commands::init::execute(global, local, cli.verbose),
 
         Commands::Validate { project, fix } => {
             commands::validate::execute(&project, fix, cli.verbose)
         }
+
+        Commands::Install { project } => commands::install::execute(&project, cli.verbose),
+
+        Commands::Workspace { action } => match action {
+            WorkspaceAction::Init { root } => {
+                commands::workspace::init(std::path::Path::new(&root), cli.verbose)
+            }
+            WorkspaceAction::List { root } => {
+                commands::workspace::list(std::path::Path::new(&root), cli.verbose)
+            }
+            WorkspaceAction::Add { member, root } => {
+                commands::workspace::add_member(std::path::Path::new(&root), &member, cli.verbose)
+            }
+            WorkspaceAction::Remove { member, root } => {
+                commands::workspace::remove_member(std::path::Path::new(&root), &member, cli.verbose)
+            }
+            WorkspaceAction::Sync { root } => {
+                commands::workspace::sync(std::path::Path::new(&root), cli.verbose)
+            }
+        },
+
+        Commands::Watch {
+            project,
+            configuration,
+            launch,
+        } => commands::watch::execute(&project, &configuration, launch, cli.verbose),
     }
 }
@@ -0,0 +1,79 @@
+//! Renders a CocoaPods `Podfile` from addons' aggregated pod dependencies
+//! and, if CocoaPods is installed, runs `pod install`.
+
+use crate::addon_manifest::PodDependency;
+use crate::error::{GeneratorError, Result};
+use std::path::Path;
+use std::process::Command;
+
+pub fn generate_podfile(
+    project_path: &Path,
+    project_name: &str,
+    min_macos: &str,
+    pods: &[PodDependency],
+) -> Result<()> {
+    let pod_lines = pods
+        .iter()
+        .map(|pod| match &pod.version {
+            Some(version) => format!("  pod '{}', '{}'", pod.name, version),
+            None => format!("  pod '{}'", pod.name),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let content = format!(
+        r#"platform :osx, '{}'
+
+target '{}' do
+  use_frameworks!
+
+{}
+end
+"#,
+        min_macos, project_name, pod_lines
+    );
+
+    std::fs::write(project_path.join("Podfile"), content)?;
+    Ok(())
+}
+
+pub fn is_pod_installed() -> bool {
+    Command::new("pod")
+        .arg("--version")
+        .output()
+        .map(|output| output.status.success())
+        .unwrap_or(false)
+}
+
+/// Runs `pod install` in `project_path` if CocoaPods is available; warns
+/// (without failing the whole `new` run) if it isn't.
+pub fn run_pod_install(project_path: &Path, verbose: bool) -> Result<()> {
+    if !is_pod_installed() {
+        eprintln!(
+            "Warning: CocoaPods ('pod') not found, skipping `pod install`. \
+             Install with: sudo gem install cocoapods"
+        );
+        return Ok(());
+    }
+
+    if verbose {
+        println!("Running pod install...");
+    }
+
+    let output = Command::new("pod")
+        .arg("install")
+        .current_dir(project_path)
+        .output()
+        .map_err(|e| GeneratorError::Other(format!("Failed to run pod install: {}", e)))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(GeneratorError::Other(format!(
+            "pod install failed: {}",
+            stderr.trim()
+        )));
+    }
+
+    println!("✅ pod install completed");
+    Ok(())
+}
@@ -0,0 +1,33 @@
+//! Target type for generated projects: an executable app, or a reusable
+//! framework/static library that other generated apps can link against as
+//! an addon instead of vendoring source.
+
+use crate::error::{GeneratorError, Result};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ProjectType {
+    App,
+    Framework,
+    StaticLib,
+}
+
+impl ProjectType {
+    pub fn parse(raw: &str) -> Result<Self> {
+        match raw {
+            "app" => Ok(ProjectType::App),
+            "framework" => Ok(ProjectType::Framework),
+            "static-lib" => Ok(ProjectType::StaticLib),
+            _ => Err(GeneratorError::Other(format!(
+                "Invalid type: {}. Must be 'app', 'framework', or 'static-lib'",
+                raw
+            ))),
+        }
+    }
+
+    /// `framework`/`static-lib` projects skip the app-loop scaffolding
+    /// (SwiftUI entry, `ofBaseApp`, `ofCreateApp`, `NSApplication` plist keys)
+    /// that only makes sense for an executable.
+    pub fn is_library(&self) -> bool {
+        !matches!(self, ProjectType::App)
+    }
+}
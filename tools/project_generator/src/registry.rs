@@ -0,0 +1,305 @@
+//! Remote addon registry: index manifests, mirror selection, and hash verification.
+//!
+//! A registry serves a JSON index describing available addons and, per
+//! resolved version, a descriptor with mirror URLs and integrity hashes. This
+//! mirrors the shape of a sparse package index (e.g. cargo's): the index is
+//! cheap to fetch in full, while per-version files are fetched on demand.
+
+use crate::error::{GeneratorError, Result};
+use serde::{Deserialize, Serialize};
+use sha2::Digest;
+use std::collections::HashMap;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+/// Digest algorithm name (`"sha256"`, `"sha512"`) to expected hex digest.
+pub type Hashes = HashMap<String, String>;
+
+/// An addon as it appears in a registry's index manifest.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegistryAddon {
+    pub id: String,
+    pub namespace: String,
+    pub versions: Vec<String>,
+    #[serde(default)]
+    pub meta: Option<Meta>,
+}
+
+/// Free-form descriptive metadata carried alongside an addon or a release.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct Meta {
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub homepage: Option<String>,
+    #[serde(default)]
+    pub license: Option<String>,
+}
+
+/// Identifies a single addon release: `namespace/id@version`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AddonDescriptor {
+    pub namespace: String,
+    pub id: String,
+    pub version: String,
+}
+
+impl AddonDescriptor {
+    /// Parse `namespace/id[@version]`. Version defaults to `"latest"`.
+    pub fn parse(spec: &str) -> Result<Self> {
+        let (ns_id, version) = match spec.split_once('@') {
+            Some((left, v)) => (left, v.to_string()),
+            None => (spec, "latest".to_string()),
+        };
+        let (namespace, id) = ns_id.split_once('/').ok_or_else(|| {
+            GeneratorError::AddonNotFound(format!(
+                "Addon spec '{}' must be namespace/id[@version]",
+                spec
+            ))
+        })?;
+        Ok(Self {
+            namespace: namespace.to_string(),
+            id: id.to_string(),
+            version,
+        })
+    }
+}
+
+/// Per-version release metadata: ordered mirror list plus integrity hashes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddonFile {
+    pub link: Vec<String>,
+    #[serde(default)]
+    pub hashes: Option<Hashes>,
+    #[serde(default)]
+    pub meta: Option<Meta>,
+    /// Other addons this version requires, as `namespace/id` -> a semver
+    /// requirement string (e.g. `"^1.2"`). See `deps.rs` for resolution.
+    #[serde(default)]
+    pub dependencies: HashMap<String, String>,
+}
+
+/// The top-level JSON document served by a registry's index.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct RegistryIndex {
+    #[serde(default)]
+    pub addons: Vec<RegistryAddon>,
+}
+
+impl RegistryIndex {
+    pub fn find(&self, namespace: &str, id: &str) -> Option<&RegistryAddon> {
+        self.addons
+            .iter()
+            .find(|a| a.namespace == namespace && a.id == id)
+    }
+}
+
+/// Loads a `[[registry]]` entry's index manifest: an `http(s)` `url` has
+/// `index` (default `"index.json"`) joined onto it and is fetched over the
+/// network; anything else is treated as a local path (a git checkout or a
+/// plain directory) and its index file is read straight off disk.
+pub fn load_named_index(reg: &crate::config::RegistryConfig) -> Result<RegistryIndex> {
+    let index_file = reg.index.as_deref().unwrap_or("index.json");
+    if reg.url.starts_with("http://") || reg.url.starts_with("https://") {
+        let url = format!("{}/{}", reg.url.trim_end_matches('/'), index_file);
+        fetch_index(&url)
+    } else {
+        let path = Path::new(&reg.url).join(index_file);
+        let content = std::fs::read_to_string(&path).map_err(GeneratorError::Io)?;
+        serde_json::from_str(&content).map_err(|e| {
+            GeneratorError::Other(format!("Invalid registry manifest at {}: {}", path.display(), e))
+        })
+    }
+}
+
+/// Fetch a registry's index manifest from `url`.
+pub fn fetch_index(url: &str) -> Result<RegistryIndex> {
+    let bytes = fetch_url(url)?;
+    serde_json::from_slice(&bytes)
+        .map_err(|e| GeneratorError::Other(format!("Invalid registry manifest at {}: {}", url, e)))
+}
+
+/// Fetch the per-version file descriptor for `descriptor`.
+///
+/// Registries are expected to serve this at
+/// `<index_url>/<namespace>/<id>/<version>.json`.
+pub fn fetch_addon_file(index_url: &str, descriptor: &AddonDescriptor) -> Result<AddonFile> {
+    let url = format!(
+        "{}/{}/{}/{}.json",
+        index_url.trim_end_matches('/'),
+        descriptor.namespace,
+        descriptor.id,
+        descriptor.version
+    );
+    let bytes = fetch_url(&url)?;
+    serde_json::from_slice(&bytes)
+        .map_err(|e| GeneratorError::Other(format!("Invalid addon file at {}: {}", url, e)))
+}
+
+/// Everything worth recording about a fetched addon: its resolved
+/// descriptor, where it was cached, which mirror served it, and the sha256 of
+/// the bytes actually downloaded (used by the lockfile regardless of whether
+/// the registry itself declared hashes).
+#[derive(Debug, Clone)]
+pub struct ResolvedAddon {
+    pub descriptor: AddonDescriptor,
+    pub path: PathBuf,
+    pub url: String,
+    pub sha256: String,
+}
+
+/// Extracts a gzipped tarball (the format every mirror in this codebase
+/// serves addons as) into `dest_dir`, replacing anything already there so a
+/// re-resolved version doesn't leave stale files from an older one behind.
+pub fn extract_archive(bytes: &[u8], dest_dir: &Path) -> Result<()> {
+    if dest_dir.exists() {
+        std::fs::remove_dir_all(dest_dir)?;
+    }
+    std::fs::create_dir_all(dest_dir)?;
+
+    let decoder = flate2::read::GzDecoder::new(bytes);
+    let mut archive = tar::Archive::new(decoder);
+    archive.unpack(dest_dir).map_err(|e| {
+        GeneratorError::Other(format!(
+            "Failed to extract addon archive into {}: {}",
+            dest_dir.display(),
+            e
+        ))
+    })?;
+    Ok(())
+}
+
+/// Resolve `descriptor` against `index`, download it from the first reachable
+/// mirror, verify every declared hash, and extract it into a real
+/// `<cache_dir>/<id>/` directory so build-file generation and `compile_db`
+/// (which only discover addon *directories*) actually see it.
+pub fn resolve_and_fetch(
+    index: &RegistryIndex,
+    index_url: &str,
+    descriptor: &AddonDescriptor,
+    cache_dir: &Path,
+) -> Result<ResolvedAddon> {
+    let entry = index
+        .find(&descriptor.namespace, &descriptor.id)
+        .ok_or_else(|| {
+            GeneratorError::AddonNotFound(format!("{}/{}", descriptor.namespace, descriptor.id))
+        })?;
+
+    let version = if descriptor.version == "latest" {
+        entry.versions.last().cloned().ok_or_else(|| {
+            GeneratorError::AddonNotFound(format!(
+                "{}/{} has no published versions",
+                descriptor.namespace, descriptor.id
+            ))
+        })?
+    } else {
+        entry
+            .versions
+            .iter()
+            .find(|v| *v == &descriptor.version)
+            .cloned()
+            .ok_or_else(|| {
+                GeneratorError::AddonNotFound(format!(
+                    "{}/{}@{} not found (available: {})",
+                    descriptor.namespace,
+                    descriptor.id,
+                    descriptor.version,
+                    entry.versions.join(", ")
+                ))
+            })?
+    };
+
+    let resolved = AddonDescriptor {
+        namespace: descriptor.namespace.clone(),
+        id: descriptor.id.clone(),
+        version: version.clone(),
+    };
+
+    let file = fetch_addon_file(index_url, &resolved)?;
+    let (bytes, used_mirror) = download_from_mirrors(&file.link)?;
+    if let Some(hashes) = &file.hashes {
+        verify_hashes(&bytes, hashes)?;
+    }
+    let sha256 = hex_digest(sha2::Sha256::new(), &bytes);
+
+    std::fs::create_dir_all(cache_dir)?;
+    let dest = cache_dir.join(&resolved.id);
+    extract_archive(&bytes, &dest)?;
+    Ok(ResolvedAddon {
+        descriptor: resolved,
+        path: dest,
+        url: used_mirror,
+        sha256,
+    })
+}
+
+/// Download `url` directly (no mirror fallback, no index lookup) and verify
+/// the bytes match `expected_sha256`. Used by `install`/`restore` to
+/// re-materialize an addon pinned in `oflike.lock` without touching the
+/// registry index.
+pub fn fetch_and_verify(url: &str, expected_sha256: &str) -> Result<Vec<u8>> {
+    let bytes = fetch_url(url)?;
+    let actual = hex_digest(sha2::Sha256::new(), &bytes);
+    if !actual.eq_ignore_ascii_case(expected_sha256) {
+        return Err(GeneratorError::HashMismatch(format!(
+            "sha256 mismatch for {}: expected {}, got {}",
+            url, expected_sha256, actual
+        )));
+    }
+    Ok(bytes)
+}
+
+fn download_from_mirrors(mirrors: &[String]) -> Result<(Vec<u8>, String)> {
+    let mut last_err = None;
+    for mirror in mirrors {
+        match fetch_url(mirror) {
+            Ok(bytes) => return Ok((bytes, mirror.clone())),
+            Err(e) => last_err = Some(e),
+        }
+    }
+    Err(last_err
+        .unwrap_or_else(|| GeneratorError::Other("No mirrors listed for addon".to_string())))
+}
+
+fn fetch_url(url: &str) -> Result<Vec<u8>> {
+    let response = ureq::get(url)
+        .call()
+        .map_err(|e| GeneratorError::Other(format!("Failed to fetch {}: {}", url, e)))?;
+    let mut bytes = Vec::new();
+    response
+        .into_reader()
+        .read_to_end(&mut bytes)
+        .map_err(GeneratorError::Io)?;
+    Ok(bytes)
+}
+
+fn verify_hashes(bytes: &[u8], hashes: &Hashes) -> Result<()> {
+    for (algo, expected) in hashes {
+        let actual = match algo.as_str() {
+            "sha256" => hex_digest(sha2::Sha256::new(), bytes),
+            "sha512" => hex_digest(sha2::Sha512::new(), bytes),
+            other => {
+                return Err(GeneratorError::Other(format!(
+                    "Unsupported hash algorithm: {}",
+                    other
+                )))
+            }
+        };
+        if !actual.eq_ignore_ascii_case(expected) {
+            return Err(GeneratorError::HashMismatch(format!(
+                "{} mismatch: expected {}, got {}",
+                algo, expected, actual
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn hex_digest<D: Digest>(mut hasher: D, bytes: &[u8]) -> String {
+    hasher.update(bytes);
+    hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
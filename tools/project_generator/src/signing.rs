@@ -0,0 +1,205 @@
+//! Resolves a `--signing` mode into a concrete identity/entitlements pair
+//! and renders `resources/<project>.entitlements`, so generated projects can
+//! be signed and run with hardened-runtime/sandbox features instead of the
+//! generator's previous hardcoded `CODE_SIGNING_ALLOWED: NO`.
+
+use crate::error::{GeneratorError, Result};
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SigningMode {
+    /// No signing; `CODE_SIGNING_ALLOWED: NO` as before.
+    None,
+    /// Sign with the ad-hoc identity (`-`).
+    AdHoc,
+    /// Auto-detect a development identity via `security find-identity`.
+    Development,
+    /// Sign with an explicit identity (SHA-1 hash or display name).
+    Identity(String),
+}
+
+impl SigningMode {
+    pub fn parse(raw: &str) -> Self {
+        match raw {
+            "none" => SigningMode::None,
+            "adhoc" => SigningMode::AdHoc,
+            "development" => SigningMode::Development,
+            other => SigningMode::Identity(other.to_string()),
+        }
+    }
+}
+
+/// Which entitlement keys to toggle on, selected via CLI flags.
+#[derive(Debug, Clone, Default)]
+pub struct EntitlementsConfig {
+    pub app_sandbox: bool,
+    pub hardened_runtime: bool,
+    pub jit: bool,
+    pub camera: bool,
+    pub microphone: bool,
+    pub network_client: bool,
+    pub network_server: bool,
+}
+
+impl EntitlementsConfig {
+    fn any_enabled(&self) -> bool {
+        self.app_sandbox
+            || self.jit
+            || self.camera
+            || self.microphone
+            || self.network_client
+            || self.network_server
+    }
+}
+
+/// The identity and entitlements actually wired into the generated build
+/// files, after auto-detection.
+pub struct ResolvedSigning {
+    pub identity: String,
+    pub hardened_runtime: bool,
+    pub entitlements_path: Option<String>,
+}
+
+/// Resolves `mode` to a concrete signing identity, and renders an
+/// entitlements file under `resources/` if signing is enabled (any mode but
+/// `none`) and any entitlement is requested.
+pub fn resolve(
+    project_path: &Path,
+    project_name: &str,
+    mode: &SigningMode,
+    entitlements: &EntitlementsConfig,
+) -> Result<ResolvedSigning> {
+    let (identity, hardened_runtime) = match mode {
+        SigningMode::None => (String::new(), false),
+        SigningMode::AdHoc => ("-".to_string(), entitlements.hardened_runtime),
+        SigningMode::Identity(id) => (id.clone(), entitlements.hardened_runtime),
+        SigningMode::Development => {
+            let detected = detect_development_identity()?;
+            match detected {
+                Some(identity) => (identity, entitlements.hardened_runtime),
+                None => {
+                    eprintln!(
+                        "Warning: no codesigning identity found via `security find-identity`, \
+                         falling back to ad-hoc signing"
+                    );
+                    ("-".to_string(), entitlements.hardened_runtime)
+                }
+            }
+        }
+    };
+
+    let entitlements_path = if *mode != SigningMode::None && entitlements.any_enabled() {
+        Some(generate_entitlements(
+            project_path,
+            project_name,
+            entitlements,
+        )?)
+    } else {
+        None
+    };
+
+    Ok(ResolvedSigning {
+        identity,
+        hardened_runtime,
+        entitlements_path,
+    })
+}
+
+/// Runs `security find-identity -v -p codesigning` and picks the first
+/// non-revoked match, preferring "Apple Development" then
+/// "Developer ID Application".
+fn detect_development_identity() -> Result<Option<String>> {
+    let output = Command::new("security")
+        .args(["find-identity", "-v", "-p", "codesigning"])
+        .output()
+        .map_err(|e| {
+            GeneratorError::Other(format!("Failed to run security find-identity: {}", e))
+        })?;
+
+    if !output.status.success() {
+        return Ok(None);
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let candidates: Vec<(String, String)> =
+        stdout.lines().filter_map(parse_identity_line).collect();
+
+    let preferred = candidates
+        .iter()
+        .find(|(_, name)| name.contains("Apple Development"))
+        .or_else(|| {
+            candidates
+                .iter()
+                .find(|(_, name)| name.contains("Developer ID Application"))
+        })
+        .or_else(|| candidates.first());
+
+    Ok(preferred.map(|(sha1, _)| sha1.clone()))
+}
+
+/// Parses a `security find-identity` line of the form:
+///   `  1) 0123456789ABCDEF0123456789ABCDEF01234567 "Apple Development: Name (TEAMID)"`
+fn parse_identity_line(line: &str) -> Option<(String, String)> {
+    let line = line.trim();
+    if line.contains("(-)") || line.contains("CSSMERR") {
+        return None;
+    }
+    let after_paren = line.split_once(") ")?.1;
+    let (sha1, rest) = after_paren.split_once(' ')?;
+    if sha1.len() != 40 || !sha1.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let name = rest.trim().trim_matches('"').to_string();
+    Some((sha1.to_string(), name))
+}
+
+fn generate_entitlements(
+    project_path: &Path,
+    project_name: &str,
+    entitlements: &EntitlementsConfig,
+) -> Result<String> {
+    let mut keys = String::new();
+    let mut push = |key: &str, value: bool| {
+        keys.push_str(&format!(
+            "    <key>{}</key>\n    <{}/>\n",
+            key,
+            if value { "true" } else { "false" }
+        ));
+    };
+    push("com.apple.security.app-sandbox", entitlements.app_sandbox);
+    push(
+        "com.apple.security.cs.allow-jit",
+        entitlements.jit,
+    );
+    push("com.apple.security.device.camera", entitlements.camera);
+    push(
+        "com.apple.security.device.microphone",
+        entitlements.microphone,
+    );
+    push(
+        "com.apple.security.network.client",
+        entitlements.network_client,
+    );
+    push(
+        "com.apple.security.network.server",
+        entitlements.network_server,
+    );
+
+    let content = format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+{}</dict>
+</plist>
+"#,
+        keys
+    );
+
+    let relative_path = format!("resources/{}.entitlements", project_name);
+    fs::write(project_path.join(&relative_path), content)?;
+
+    Ok(relative_path)
+}
@@ -0,0 +1,81 @@
+//! Recognizes whether a generated build file still matches a known template
+//! version, the way rustc's bootstrap tracks config schema versions by hash.
+//!
+//! `new` records the SHA-256 of each build file it writes (after
+//! [`normalize`] strips out this project's own substitutions) into the
+//! `[generated]` table of `oflike.toml`. `validate --fix` rehashes the file
+//! on disk: if the hash is still in `KNOWN_CMAKE_HASHES`, nothing but the
+//! template changed underneath it, so it's safe to regenerate; if not, the
+//! user edited it and `validate --fix` must refuse to overwrite it.
+
+use sha2::{Digest, Sha256};
+
+/// Every hash `generate_cmake_file` has produced for a default (no addons,
+/// `App` project type, `native` arch, unsigned) project, oldest first. Append
+/// a new entry here whenever the CMakeLists.txt template changes -- never
+/// remove one, or `validate --fix` stops recognizing projects generated by
+/// older versions.
+///
+/// `project.yml` isn't tracked here: its content also depends on whether a
+/// prebuilt `liboflike-metal.a` exists on this machine, so the same template
+/// version doesn't hash identically across checkouts. Its hash is still
+/// recorded in `[generated]` for visibility, but `validate --fix` will always
+/// treat it as unrecognized (i.e. never auto-regenerate it) until that's
+/// addressed.
+pub const KNOWN_CMAKE_HASHES: &[&str] = &[
+    // Deployment target clamped to the SDK version (SDK 13.0 or older), so
+    // it normalizes to `__SDK_VERSION__`.
+    "a43d8a7d739efaaf1d3772882f3d4e53fe3dcf7eafe0d40dc5b555a7d20fb007",
+    // Same template, unclamped `13.0` deployment target (any newer SDK).
+    "6dbf9cda46a2d84ee1954dc794bbdf5b32d7758ae3ada030e5bd2d1873b6c095",
+];
+
+/// This project's own substitutions, so the same template version hashes
+/// identically regardless of which project it was generated for.
+pub struct Substitutions<'a> {
+    pub project_name: &'a str,
+    pub bundle_id: &'a str,
+    pub sdk_path: &'a str,
+    pub sdk_version: &'a str,
+    pub signing_identity: &'a str,
+}
+
+/// Replaces every occurrence of this project's own values with fixed
+/// placeholder tokens. Anything the substitution list doesn't cover (addon
+/// blocks, non-default project types, environment-dependent sections) is
+/// left as-is -- which simply means its hash won't appear in
+/// `KNOWN_CMAKE_HASHES`, and `validate --fix` correctly treats that as
+/// "can't verify, don't touch".
+pub fn normalize(content: &str, subs: &Substitutions) -> String {
+    let mut normalized = content.to_string();
+    if !subs.project_name.is_empty() {
+        normalized = normalized.replace(subs.project_name, "__PROJECT_NAME__");
+    }
+    if !subs.bundle_id.is_empty() {
+        normalized = normalized.replace(subs.bundle_id, "__BUNDLE_ID__");
+    }
+    if !subs.sdk_path.is_empty() {
+        normalized = normalized.replace(subs.sdk_path, "__SDK_PATH__");
+    }
+    if !subs.sdk_version.is_empty() {
+        normalized = normalized.replace(subs.sdk_version, "__SDK_VERSION__");
+    }
+    if !subs.signing_identity.is_empty() {
+        normalized = normalized.replace(subs.signing_identity, "__SIGNING_IDENTITY__");
+    }
+    normalized
+}
+
+pub fn hash(content: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(content.as_bytes());
+    hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+pub fn is_known_cmake_template(hash: &str) -> bool {
+    KNOWN_CMAKE_HASHES.contains(&hash)
+}
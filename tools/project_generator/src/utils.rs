@@ -63,9 +63,9 @@ pub fn validate_entry_mode(mode: &str) -> Result<()> {
 /// Validate addon mode
 pub fn validate_addon_mode(mode: &str) -> Result<()> {
     match mode {
-        "reference" | "copy" | "symlink" => Ok(()),
+        "reference" | "copy" | "symlink" | "vendor" => Ok(()),
         _ => Err(GeneratorError::InvalidAddonMode(format!(
-            "Invalid addon mode: {}. Must be 'reference', 'copy', or 'symlink'",
+            "Invalid addon mode: {}. Must be 'reference', 'copy', 'symlink', or 'vendor'",
             mode
         ))),
     }
@@ -82,6 +82,17 @@ pub fn validate_template(template: &str) -> Result<()> {
     }
 }
 
+/// Validate project-generator backend name
+pub fn validate_generator(generator: &str) -> Result<()> {
+    match generator {
+        "xcodegen" | "swiftpm" | "cmake" => Ok(()),
+        _ => Err(GeneratorError::InvalidGenerator(format!(
+            "Invalid generator: {}. Must be 'xcodegen', 'swiftpm', or 'cmake'",
+            generator
+        ))),
+    }
+}
+
 /// Convert project name to PascalCase
 pub fn to_pascal_case(name: &str) -> String {
     name.split('-')
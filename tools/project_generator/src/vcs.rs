@@ -0,0 +1,88 @@
+//! Pluggable version-control backends for `new`, mirroring the variety
+//! Cargo's `cargo_new` module supports instead of hardcoding git.
+
+use crate::error::{GeneratorError, Result};
+use std::path::Path;
+use std::process::Command;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VersionControl {
+    Git,
+    Hg,
+    Fossil,
+    Pijul,
+    None,
+}
+
+impl VersionControl {
+    pub fn parse(raw: &str) -> Result<Self> {
+        match raw {
+            "git" => Ok(VersionControl::Git),
+            "hg" => Ok(VersionControl::Hg),
+            "fossil" => Ok(VersionControl::Fossil),
+            "pijul" => Ok(VersionControl::Pijul),
+            "none" => Ok(VersionControl::None),
+            _ => Err(GeneratorError::Other(format!(
+                "Invalid vcs: {}. Must be 'git', 'hg', 'fossil', 'pijul', or 'none'",
+                raw
+            ))),
+        }
+    }
+
+    /// Ignore-file name this backend looks for at the project root; `None`
+    /// means no ignore file should be written.
+    pub fn ignore_file_name(&self) -> Option<&'static str> {
+        match self {
+            VersionControl::Git => Some(".gitignore"),
+            VersionControl::Hg => Some(".hgignore"),
+            VersionControl::Fossil => Some(".fossil-settings/ignore-glob"),
+            VersionControl::Pijul => Some(".ignore"),
+            VersionControl::None => None,
+        }
+    }
+
+    /// Runs this backend's `init` invocation in `project_path`. No-op for
+    /// `none`.
+    pub fn init(&self, project_path: &Path, verbose: bool) -> Result<()> {
+        let program = match self {
+            VersionControl::Git => "git",
+            VersionControl::Hg => "hg",
+            VersionControl::Fossil => "fossil",
+            VersionControl::Pijul => "pijul",
+            VersionControl::None => return Ok(()),
+        };
+
+        if verbose {
+            println!("Initializing {} repository...", program);
+        }
+
+        let succeeded = match self {
+            // `fossil init` creates a repository database file; `fossil
+            // open` then checks it out into the current directory.
+            VersionControl::Fossil => {
+                let repo_file = project_path.join(".fossil");
+                run(Command::new("fossil").arg("init").arg(&repo_file).current_dir(project_path))?
+                    && run(
+                        Command::new("fossil")
+                            .arg("open")
+                            .arg(&repo_file)
+                            .current_dir(project_path),
+                    )?
+            }
+            _ => run(Command::new(program).arg("init").current_dir(project_path))?,
+        };
+
+        if !succeeded {
+            return Err(GeneratorError::Other(format!(
+                "Failed to initialize {} repository",
+                program
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+fn run(cmd: &mut Command) -> Result<bool> {
+    Ok(cmd.output()?.status.success())
+}
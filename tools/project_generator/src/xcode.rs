@@ -0,0 +1,88 @@
+//! Probes the local Xcode toolchain via `xcrun`/`xcode-select` so generated
+//! projects target the SDK actually installed on this machine, instead of
+//! a version and framework list hardcoded at generator-build time.
+
+use crate::error::{GeneratorError, Result};
+use std::process::Command;
+
+#[derive(Debug, Clone)]
+pub struct XcodeEnvironment {
+    pub sdk_path: String,
+    pub sdk_version: String,
+    pub developer_dir: String,
+}
+
+/// Discover the installed macOS SDK path/version and developer dir, and
+/// fail fast if the Metal compiler isn't available.
+pub fn probe() -> Result<XcodeEnvironment> {
+    let sdk_path = run_xcrun(&["--sdk", "macosx", "--show-sdk-path"])?;
+    let sdk_version = run_xcrun(&["--sdk", "macosx", "--show-sdk-version"])?;
+    let developer_dir = run_command("xcode-select", &["-p"])?;
+
+    check_metal_compiler()?;
+
+    Ok(XcodeEnvironment {
+        sdk_path,
+        sdk_version,
+        developer_dir,
+    })
+}
+
+/// Caps `requested` (e.g. `BuildConfig::min_macos`) at the installed SDK
+/// version, so the generated deployment target is never newer than what
+/// this machine can actually build against.
+pub fn clamp_deployment_target(requested: &str, env: &XcodeEnvironment) -> String {
+    match (parse_version(requested), parse_version(&env.sdk_version)) {
+        (Some(req), Some(sdk)) if req > sdk => env.sdk_version.clone(),
+        _ => requested.to_string(),
+    }
+}
+
+fn parse_version(v: &str) -> Option<f32> {
+    v.split('.').take(2).collect::<Vec<_>>().join(".").parse().ok()
+}
+
+fn run_xcrun(args: &[&str]) -> Result<String> {
+    run_command("xcrun", args)
+}
+
+fn run_command(program: &str, args: &[&str]) -> Result<String> {
+    let output = Command::new(program).args(args).output().map_err(|e| {
+        if e.kind() == std::io::ErrorKind::NotFound {
+            GeneratorError::Other(
+                "Xcode command line tools not found. Install with: xcode-select --install"
+                    .to_string(),
+            )
+        } else {
+            GeneratorError::Other(format!("Failed to run {}: {}", program, e))
+        }
+    })?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(GeneratorError::Other(format!(
+            "{} {} failed: {}",
+            program,
+            args.join(" "),
+            stderr.trim()
+        )));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+fn check_metal_compiler() -> Result<()> {
+    let output = Command::new("xcrun")
+        .args(["metal", "-v"])
+        .output()
+        .map_err(|e| GeneratorError::Other(format!("Failed to run xcrun metal -v: {}", e)))?;
+
+    if !output.status.success() {
+        return Err(GeneratorError::Other(
+            "Metal compiler not found. Install the Metal Toolchain component (Xcode Settings > \
+             Components) and verify `xcrun metal -v` works."
+                .to_string(),
+        ));
+    }
+    Ok(())
+}